@@ -0,0 +1,125 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to a part, whatever type the day computes it in.
+///
+/// Numbers are kept canonical, so two answers are equal exactly when they print the same.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    /// Only for numbers above `i128::MAX`.
+    BigUint(u128),
+    Text(String),
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),+) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(i128::from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or(Answer::BigUint(value), Answer::Int)
+    }
+}
+
+/// Text that is a number, written the way numbers print, becomes that number.
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        match value.parse() {
+            Ok(Answer::Text(_)) | Err(_) => Answer::Text(value),
+            Ok(number) => number,
+        }
+    }
+}
+
+/// Text that is a number, written the way numbers print, becomes that number.
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        let Ok(answer) = value.parse();
+        answer
+    }
+}
+
+/// Reads a recorded answer: a number if it prints back the same, text otherwise, so `007` and
+/// `+7` stay text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match (s.parse::<i128>(), s.parse::<u128>()) {
+            (Ok(int), _) if int.to_string() == s => Answer::Int(int),
+            (_, Ok(uint)) if uint.to_string() == s => Answer::from(uint),
+            _ => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(int) => write!(f, "{int}"),
+            Answer::BigUint(uint) => write!(f, "{uint}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn integer_widths_compare_equal() {
+        assert_eq!(
+            Answer::from(6479180385864i128),
+            Answer::from(6479180385864u64)
+        );
+        assert_eq!(Answer::from(21usize), Answer::from(21i32));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn recorded_answers_parse_back() {
+        assert_eq!("-12".parse::<Answer>(), Ok(Answer::from(-12)));
+        assert_eq!(
+            u128::MAX.to_string().parse::<Answer>(),
+            Ok(Answer::from(u128::MAX))
+        );
+        assert_eq!(
+            "EZFH".parse::<Answer>(),
+            Ok(Answer::Text("EZFH".to_string()))
+        );
+    }
+
+    #[test]
+    fn numeric_text_equals_the_number() {
+        assert_eq!(Answer::from("123"), Answer::from(123));
+        assert_eq!(Answer::from("-4".to_string()), Answer::from(-4));
+        for text in ["007", "+7", "-0", "1 2"] {
+            assert_eq!(Answer::from(text), Answer::Text(text.to_string()));
+        }
+    }
+}
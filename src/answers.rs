@@ -0,0 +1,277 @@
+use crate::parse::{expected, line_end, parse_input};
+use crate::{Answer, AocError};
+use std::collections::BTreeMap;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::{dec_uint, digit1, space0, till_line_ending};
+use winnow::combinator::{alt, delimited, opt, preceded, separated_pair, terminated};
+use winnow::token::{take_till, take_while};
+
+/// The name of the personal puzzle input, `inputs/NN.in`.
+pub const PERSONAL_INPUT: &str = "input";
+
+/// The name of the sample from the puzzle statement, `inputs/NN_sample.in`.
+pub const SAMPLE: &str = "sample";
+
+/// The answer recorded for a day, part and input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Known(Answer),
+    /// The right answer, which the solver is known to get wrong, and why.
+    KnownFailure {
+        answer: Answer,
+        reason: String,
+    },
+    /// Nobody has confirmed the answer yet.
+    Unknown,
+}
+
+impl Expected {
+    /// The right answer, if it's known.
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Expected::Known(answer) | Expected::KnownFailure { answer, .. } => Some(answer),
+            Expected::Unknown => None,
+        }
+    }
+}
+
+/// The known answers, as recorded in `answers.toml`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    /// Keyed by day, input name and part.
+    entries: BTreeMap<(u8, String, u8), Expected>,
+}
+
+/// The file in `inputs/` holding the input named `name` for `day`.
+pub fn input_file(day: u8, name: &str) -> String {
+    if name == PERSONAL_INPUT {
+        format!("{day:02}.in")
+    } else {
+        format!("{day:02}_{name}.in")
+    }
+}
+
+/// Samples come from the puzzle statements and are kept in the repository.
+pub fn is_sample(name: &str) -> bool {
+    name.contains("sample")
+}
+
+struct Entry {
+    input: String,
+    part: u8,
+    expected: Expected,
+}
+
+fn parse_comment(input: &mut &str) -> Result<()> {
+    (space0, opt(('#', till_line_ending)))
+        .void()
+        .parse_next(input)
+}
+
+fn parse_blank_line(input: &mut &str) -> Result<()> {
+    terminated(parse_comment, line_end).parse_next(input)
+}
+
+fn parse_header(input: &mut &str) -> Result<u8> {
+    delimited("[day", digit1.parse_to(), ']')
+        .context(expected("section such as `[day01]`"))
+        .parse_next(input)
+}
+
+fn parse_quoted<'s>(input: &mut &'s str) -> Result<&'s str> {
+    delimited('"', take_till(0.., ['"', '\n']), '"').parse_next(input)
+}
+
+fn parse_answer(input: &mut &str) -> Result<Expected> {
+    alt((
+        (opt('-'), digit1).take().parse_to().map(Expected::Known),
+        parse_quoted.map(|answer: &str| match answer {
+            "unknown" => Expected::Unknown,
+            _ => Expected::Known(answer.into()),
+        }),
+    ))
+    .parse_next(input)
+}
+
+/// `{ answer = 2, fails = "why" }`, for an answer the solver is known to get wrong.
+fn parse_known_failure(input: &mut &str) -> Result<Expected> {
+    let field = |name| (name, space0, '=', space0);
+
+    delimited(
+        ('{', space0),
+        separated_pair(
+            preceded(field("answer"), parse_answer),
+            (space0, ',', space0),
+            preceded(field("fails"), parse_quoted),
+        ),
+        (space0, '}'),
+    )
+    .verify_map(|(expected, reason): (Expected, &str)| match expected {
+        Expected::Known(answer) => Some(Expected::KnownFailure {
+            answer,
+            reason: reason.to_string(),
+        }),
+        _ => None,
+    })
+    .parse_next(input)
+}
+
+fn parse_value(input: &mut &str) -> Result<Expected> {
+    alt((parse_known_failure, parse_answer))
+        .context(expected("integer, quoted answer or known failure"))
+        .parse_next(input)
+}
+
+fn parse_entry(input: &mut &str) -> Result<Entry> {
+    separated_pair(
+        (
+            take_while(1.., ('a'..='z', '0'..='9', '_', '-')),
+            preceded(".part", dec_uint),
+        )
+            .context(expected("key such as `sample.part1`")),
+        (space0, '=', space0),
+        parse_value,
+    )
+    .map(|((input, part), expected): ((&str, u8), Expected)| Entry {
+        input: input.to_string(),
+        part,
+        expected,
+    })
+    .parse_next(input)
+}
+
+fn parse_answers(input: &mut &str) -> Result<Vec<(u8, Entry)>> {
+    let mut entries = Vec::new();
+    let mut day = None;
+
+    while !input.is_empty() {
+        if opt(parse_blank_line).parse_next(input)?.is_some() {
+            continue;
+        }
+
+        // Entries need a section first, so anything before one is read as a header
+        match day {
+            Some(day) if !input.starts_with('[') => {
+                entries.push((
+                    day,
+                    terminated(parse_entry, parse_blank_line).parse_next(input)?,
+                ));
+            }
+            _ => day = Some(terminated(parse_header, parse_blank_line).parse_next(input)?),
+        }
+    }
+
+    Ok(entries)
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> std::result::Result<Self, AocError> {
+        let entries = parse_input(parse_answers, input)?
+            .into_iter()
+            .map(|(day, entry)| ((day, entry.input, entry.part), entry.expected))
+            .collect();
+
+        Ok(Answers { entries })
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&Expected> {
+        self.entries.get(&(day, input.to_string(), part))
+    }
+
+    /// The input to use for `part` when asked for `name`. A part can have its own version of an
+    /// input, `N_name`, like the second sample of day 11 in `11_2_sample.in`.
+    pub fn input_for(&self, day: u8, name: &str, part: u8) -> String {
+        let own = format!("{part}_{name}");
+
+        if self.entries.contains_key(&(day, own.clone(), part)) {
+            own
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// The inputs to check `part` of `day` on: the sample, the personal input and every other
+    /// input with an answer recorded for that part. Samples come first.
+    pub fn inputs(&self, day: u8, part: u8) -> Vec<String> {
+        let mut inputs: Vec<String> = self
+            .entries
+            .keys()
+            .filter(|&&(d, _, p)| d == day && p == part)
+            .map(|(_, name, _)| name.clone())
+            .chain([SAMPLE, PERSONAL_INPUT].map(|name| self.input_for(day, name, part)))
+            .collect();
+
+        inputs.sort_by(|a, b| (a == PERSONAL_INPUT, a).cmp(&(b == PERSONAL_INPUT, b)));
+        inputs.dedup();
+        inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Expected};
+    use crate::Answer;
+
+    #[test]
+    fn reads_known_and_unknown_answers() {
+        let answers = Answers::parse(
+            "# header\n\n[day11]\nsample.part1 = 5\ninput.part2 = \"unknown\" # not solved\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.get(11, "sample", 1),
+            Some(&Expected::Known(Answer::from(5)))
+        );
+        assert_eq!(answers.get(11, "input", 2), Some(&Expected::Unknown));
+        assert_eq!(answers.get(11, "input", 1), None);
+    }
+
+    #[test]
+    fn quoted_numbers_match_numeric_answers() {
+        let answers = Answers::parse("[day03]\ninput.part1 = \"123\"\n").unwrap();
+
+        assert_eq!(
+            answers.get(3, "input", 1),
+            Some(&Expected::Known(Answer::from(123)))
+        );
+    }
+
+    #[test]
+    fn reads_known_failures() {
+        let answers =
+            Answers::parse("[day12]\nsample.part1 = { answer = 2, fails = \"heuristic\" }\n")
+                .unwrap();
+
+        assert_eq!(
+            answers.get(12, "sample", 1),
+            Some(&Expected::KnownFailure {
+                answer: Answer::from(2),
+                reason: "heuristic".to_string()
+            })
+        );
+        assert!(
+            Answers::parse("[day12]\nsample.part1 = { answer = \"unknown\", fails = \"\" }\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn part_specific_inputs_replace_shared_ones() {
+        let answers =
+            Answers::parse("[day11]\nsample.part1 = 5\n2_sample.part2 = 2\nedge.part2 = 1\n")
+                .unwrap();
+
+        assert_eq!(answers.input_for(11, "sample", 1), "sample");
+        assert_eq!(answers.input_for(11, "sample", 2), "2_sample");
+        assert_eq!(answers.inputs(11, 1), ["sample", "input"]);
+        assert_eq!(answers.inputs(11, 2), ["2_sample", "edge", "input"]);
+    }
+
+    #[test]
+    fn rejects_entry_without_value() {
+        let error = Answers::parse("[day01]\nsample.part1 =\n").unwrap_err();
+        assert_eq!(error.location().map(|l| (l.line, l.column)), Some((2, 15)));
+    }
+}
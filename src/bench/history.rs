@@ -0,0 +1,331 @@
+//! Every benchmark run, appended to a tab-separated file so later runs can be compared with it.
+//!
+//! Each record notes how the runner was built, since a debug build or one counting allocations
+//! is much slower, and only records of the same build are compared.
+
+use super::{DayBench, Stats, Step};
+use crate::AocError;
+use crate::parse::{expected, lines, parse_input};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::{dec_uint, line_ending};
+use winnow::combinator::{alt, opt, preceded, seq};
+use winnow::token::take_till;
+
+const HEADER: &str =
+    "# timestamp\trevision\tprofile\tfeatures\tday\tstep\tinput\tmin_ns\tmedian_ns\tmax_ns";
+
+/// The profile this runner was built with, `debug` or `release`.
+pub fn profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+/// The features this runner was built with that change its timings, or `-` for none.
+pub fn features() -> &'static str {
+    if cfg!(feature = "count-allocs") {
+        "count-allocs"
+    } else {
+        "-"
+    }
+}
+
+/// The timing of one step in one benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub timestamp: String,
+    pub revision: String,
+    /// The [`profile`] of the runner that made the record.
+    pub profile: String,
+    /// The [`features`] of the runner that made the record.
+    pub features: String,
+    pub day: u8,
+    pub step: Step,
+    pub input: String,
+    pub stats: Stats,
+}
+
+impl Record {
+    fn to_tsv(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.revision,
+            self.profile,
+            self.features,
+            self.day,
+            self.step,
+            self.input,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.max.as_nanos()
+        )
+    }
+}
+
+/// The records of a run made at `timestamp` on the git `revision`, by this runner's build.
+pub fn records(timestamp: &str, revision: &str, benches: &[DayBench]) -> Vec<Record> {
+    benches
+        .iter()
+        .flat_map(|bench| {
+            bench.timings.steps().map(|(step, stats)| Record {
+                timestamp: timestamp.to_string(),
+                revision: revision.to_string(),
+                profile: profile().to_string(),
+                features: features().to_string(),
+                day: bench.day,
+                step,
+                input: bench.input.clone(),
+                stats,
+            })
+        })
+        .collect()
+}
+
+fn parse_field(input: &mut &str) -> Result<String> {
+    take_till(1.., ['\t', '\r', '\n'])
+        .map(str::to_string)
+        .parse_next(input)
+}
+
+fn parse_step(input: &mut &str) -> Result<Step> {
+    alt((
+        "parse".value(Step::Parse),
+        preceded("part ", dec_uint).map(Step::Part),
+    ))
+    .context(expected("`parse` or `part N`"))
+    .parse_next(input)
+}
+
+fn parse_nanos(input: &mut &str) -> Result<Duration> {
+    dec_uint
+        .map(Duration::from_nanos)
+        .context(expected("nanoseconds"))
+        .parse_next(input)
+}
+
+fn parse_stats(input: &mut &str) -> Result<Stats> {
+    seq! {Stats { min: parse_nanos, _: '\t', median: parse_nanos, _: '\t', max: parse_nanos }}
+        .parse_next(input)
+}
+
+fn parse_record(input: &mut &str) -> Result<Record> {
+    seq! {Record {
+        timestamp: parse_field,
+        _: '\t',
+        revision: parse_field,
+        _: '\t',
+        profile: parse_field,
+        _: '\t',
+        features: parse_field,
+        _: '\t',
+        day: dec_uint,
+        _: '\t',
+        step: parse_step,
+        _: '\t',
+        input: parse_field,
+        _: '\t',
+        stats: parse_stats,
+    }}
+    .parse_next(input)
+}
+
+fn parse_history(input: &mut &str) -> Result<Vec<Record>> {
+    preceded(
+        (HEADER.context(expected("history header")), line_ending),
+        opt(lines(parse_record)).map(Option::unwrap_or_default),
+    )
+    .parse_next(input)
+}
+
+pub fn parse(input: &str) -> std::result::Result<Vec<Record>, AocError> {
+    parse_input(parse_history, input)
+}
+
+/// Appends `records` to the history at `path`, creating it if needed.
+pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{HEADER}")?;
+    }
+    for record in records {
+        writeln!(file, "{}", record.to_tsv())?;
+    }
+
+    Ok(())
+}
+
+/// A step of the current run next to its latest earlier timing.
+#[derive(Debug)]
+pub struct Comparison<'a> {
+    pub current: &'a Record,
+    pub baseline: &'a Record,
+}
+
+impl Comparison<'_> {
+    /// How much slower the median got, in percent. Negative when it got faster, and `None` when
+    /// the baseline was too fast to measure.
+    pub fn slowdown_percent(&self) -> Option<f64> {
+        let before = self.baseline.stats.median.as_secs_f64();
+        let after = self.current.stats.median.as_secs_f64();
+
+        (before > 0.0).then(|| (after - before) / before * 100.0)
+    }
+}
+
+/// Pairs every record of `current` with the latest one in `history` for the same day, step
+/// and input, made by the same build, if there is one.
+pub fn compare<'a>(history: &'a [Record], current: &'a [Record]) -> Vec<Comparison<'a>> {
+    let key = |record: &'a Record| {
+        (
+            record.day,
+            record.step,
+            &record.input,
+            &record.profile,
+            &record.features,
+        )
+    };
+
+    current
+        .iter()
+        .filter_map(|record| {
+            let baseline = history
+                .iter()
+                .rev()
+                .find(|earlier| key(earlier) == key(record))?;
+
+            Some(Comparison {
+                current: record,
+                baseline,
+            })
+        })
+        .collect()
+}
+
+/// Formats `time` as an ISO 8601 UTC timestamp, such as `2025-12-18T10:31:02Z`.
+pub fn utc_timestamp(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since the epoch, after Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Record, compare, parse, utc_timestamp};
+    use crate::bench::{Stats, Step};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn record(revision: &str, median_us: u64) -> Record {
+        let us = Duration::from_micros;
+        Record {
+            timestamp: "2025-12-18T10:31:02Z".to_string(),
+            revision: revision.to_string(),
+            profile: "release".to_string(),
+            features: "-".to_string(),
+            day: 4,
+            step: Step::Part(2),
+            input: "inputs/04.in".to_string(),
+            stats: Stats {
+                min: us(median_us - 1),
+                median: us(median_us),
+                max: us(median_us + 1),
+            },
+        }
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let records = [record("aaaaaaa", 500), record("bbbbbbb-dirty", 1000)];
+        let text = format!(
+            "{}\n{}\n{}\n",
+            super::HEADER,
+            records[0].to_tsv(),
+            records[1].to_tsv()
+        );
+
+        assert_eq!(parse(&text).unwrap(), records);
+        assert_eq!(parse(&format!("{}\n", super::HEADER)).unwrap(), []);
+    }
+
+    #[test]
+    fn compares_with_the_latest_earlier_run() {
+        let history = [record("aaaaaaa", 500), record("bbbbbbb", 400)];
+        let current = [record("ccccccc", 1000)];
+
+        let comparisons = compare(&history, &current);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].baseline.revision, "bbbbbbb");
+        assert_eq!(comparisons[0].slowdown_percent(), Some(150.0));
+    }
+
+    #[test]
+    fn only_compares_runs_of_the_same_build() {
+        let counting = Record {
+            features: "count-allocs".to_string(),
+            ..record("bbbbbbb", 4000)
+        };
+        let debug = Record {
+            profile: "debug".to_string(),
+            ..record("bbbbbbb", 9000)
+        };
+        let history = [record("aaaaaaa", 500), counting, debug];
+        let current = [record("ccccccc", 1000)];
+
+        let comparisons = compare(&history, &current);
+        assert_eq!(comparisons[0].baseline.revision, "aaaaaaa");
+
+        let instant = Record {
+            stats: Stats {
+                min: Duration::ZERO,
+                median: Duration::ZERO,
+                max: Duration::ZERO,
+            },
+            ..record("aaaaaaa", 500)
+        };
+        let history = [instant];
+        assert_eq!(compare(&history, &current)[0].slowdown_percent(), None);
+    }
+
+    #[test]
+    fn formats_utc_timestamps() {
+        let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+
+        assert_eq!(utc_timestamp(at(0)), "1970-01-01T00:00:00Z");
+        assert_eq!(utc_timestamp(at(951_782_400)), "2000-02-29T00:00:00Z");
+        assert_eq!(utc_timestamp(at(1_766_053_862)), "2025-12-18T10:31:02Z");
+        assert!(utc_timestamp(SystemTime::now()).ends_with('Z'));
+    }
+}
@@ -0,0 +1,248 @@
+use crate::memory::{self, Allocations};
+use crate::parse::normalize;
+use crate::{AocError, Solution, json};
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub mod history;
+
+/// How many times to run each step.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Runs before measuring, to warm up caches and the allocator.
+    pub warmup: u32,
+    pub runs: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 5,
+            runs: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn of(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            r#"{{"min_ns": {}, "median_ns": {}, "max_ns": {}}}"#,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+/// Something timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Parse => f.pad("parse"),
+            Step::Part(part) => f.pad(&format!("part {part}")),
+        }
+    }
+}
+
+/// Timings of a day's parse and of each part, measured separately.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    pub parse: Stats,
+    pub parts: Vec<(u8, Stats)>,
+    /// What one more run of each step allocated, only counted with the `count-allocs` feature.
+    pub allocations: Vec<(Step, Allocations)>,
+}
+
+impl Timings {
+    pub fn allocations(&self, step: Step) -> Option<Allocations> {
+        self.allocations
+            .iter()
+            .find(|(counted, _)| *counted == step)
+            .map(|&(_, allocations)| allocations)
+    }
+
+    pub fn steps(&self) -> impl Iterator<Item = (Step, Stats)> + '_ {
+        std::iter::once((Step::Parse, self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|&(part, stats)| (Step::Part(part), stats)),
+        )
+    }
+}
+
+fn measure(
+    config: &BenchConfig,
+    mut step: impl FnMut() -> Result<(), AocError>,
+) -> Result<Stats, AocError> {
+    for _ in 0..config.warmup {
+        step()?;
+    }
+
+    let samples = (0..config.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            step()?;
+            Ok(start.elapsed())
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    Ok(Stats::of(samples))
+}
+
+/// Counts what one more run of `step` allocates, after the timed ones so they run on the same
+/// warm allocator, and only when counting is enabled.
+fn count(
+    step: Step,
+    allocations: &mut Vec<(Step, Allocations)>,
+    run: impl FnOnce() -> Result<(), AocError>,
+) -> Result<(), AocError> {
+    if memory::ENABLED {
+        let (result, counted) = memory::measure(run);
+        result?;
+        allocations.extend(counted.map(|counted| (step, counted)));
+    }
+    Ok(())
+}
+
+/// Times parsing `input`, then each of `parts` on a single parsed copy of it. The input is
+/// normalized once beforehand, outside of the timings.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    config: &BenchConfig,
+) -> Result<Timings, AocError> {
+    let input = &*normalize(input);
+    let mut allocations = Vec::new();
+    let parse_step = || S::parse(black_box(input)).map(|parsed| drop(black_box(parsed)));
+    let parse = measure(config, parse_step)?;
+    count(Step::Parse, &mut allocations, parse_step)?;
+
+    let parsed = S::parse(input)?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let part_step = || {
+                let answer = match part {
+                    1 => S::part1(black_box(&parsed))?,
+                    _ => S::part2(black_box(&parsed))?,
+                };
+                black_box(answer);
+                Ok(())
+            };
+            let stats = measure(config, part_step)?;
+            count(Step::Part(part), &mut allocations, part_step)?;
+            Ok((part, stats))
+        })
+        .collect::<Result<_, AocError>>()?;
+
+    Ok(Timings {
+        parse,
+        parts,
+        allocations,
+    })
+}
+
+/// The timings of one day on one input.
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub input: String,
+    pub timings: Timings,
+}
+
+/// The allocations of a step as a JSON field named `field`, if they were counted.
+fn allocations_json(timings: &Timings, step: Step, field: &str) -> String {
+    timings
+        .allocations(step)
+        .map(|allocations| format!(r#", "{field}": {}"#, allocations.to_json()))
+        .unwrap_or_default()
+}
+
+/// Renders a benchmark run as JSON, with every duration in nanoseconds.
+pub fn to_json(config: &BenchConfig, days: &[DayBench]) -> String {
+    let days: Vec<String> = days
+        .iter()
+        .map(|bench| {
+            let parts: Vec<String> = bench
+                .timings
+                .parts
+                .iter()
+                .map(|&(part, stats)| {
+                    format!(
+                        r#"{{"part": {part}, "time": {}{}}}"#,
+                        stats.to_json(),
+                        allocations_json(&bench.timings, Step::Part(part), "allocations")
+                    )
+                })
+                .collect();
+
+            format!(
+                r#"    {{"day": {}, "input": {}, "parse": {}{}, "parts": [{}]}}"#,
+                bench.day,
+                json::string(&bench.input),
+                bench.timings.parse.to_json(),
+                allocations_json(&bench.timings, Step::Parse, "parse_allocations"),
+                parts.join(", ")
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"warmup\": {},\n  \"runs\": {},\n  \"days\": [\n{}\n  ]\n}}\n",
+        config.warmup,
+        config.runs,
+        days.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn median_of_even_samples_is_the_mean_of_the_middle_two() {
+        let ms = Duration::from_millis;
+        let stats = Stats::of(vec![ms(9), ms(1), ms(4), ms(2)]);
+
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(9)
+            }
+        );
+    }
+}
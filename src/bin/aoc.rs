@@ -0,0 +1,1246 @@
+use aoc_2025::AocError;
+use aoc_2025::answers::{self, Answers, Expected, PERSONAL_INPUT};
+use aoc_2025::bench::history;
+use aoc_2025::bench::{self, BenchConfig, DayBench};
+use aoc_2025::days::{self, Solutions, Solver};
+use aoc_2025::log::{self, Level};
+use aoc_2025::memory::{self, Bytes};
+use aoc_2025::pool;
+use aoc_2025::report::{self, Outcome, Row, Status};
+use aoc_2025::rng::Rng;
+use aoc_2025::scaffold;
+use aoc_2025::store::{self, Check, Store};
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::io::{self, ErrorKind, Read, Write};
+use std::num::NonZeroUsize;
+use std::panic;
+use std::path::Path;
+use std::process::{Command, ExitCode};
+use std::time::{Instant, SystemTime};
+
+const USAGE: &str = "usage: aoc run <day|all> [part] [--input <path>] [--report <path.md>]
+                 [--jobs <n>]
+       aoc run <day> [part] --stream [--input <path>]
+       aoc verify [day|all]
+       aoc bench <day|all> [--input <path>] [--runs <n>] [--warmup <n>] [--json <path>]
+                 [--compare] [--threshold <percent>]
+       aoc batch <day> <file>... [--part <n>] [--jobs <n>]
+       aoc new <day>
+       aoc gen <day> [--size <n>] [--seed <n>]
+       aoc input add <day> [--sample] [--name <name>] [--force] < <file>
+       aoc input list [day|all]
+       aoc input show <day> [--sample] [--name <name>]
+
+`--verbose` logs debugging output from the solvers to stderr, twice logs everything. The
+`AOC_LOG` environment variable sets the level too, such as `AOC_LOG=trace`.";
+
+const ANSWERS_PATH: &str = "answers.toml";
+const BENCH_JSON_PATH: &str = "bench.json";
+const BENCH_HISTORY_PATH: &str = "bench_history.tsv";
+const INPUTS_DIR: &str = "inputs";
+const TEMPLATE_PATH: &str = "src/days/template.rs";
+const DAYS_MOD_PATH: &str = "src/days/mod.rs";
+const LIB_PATH: &str = "src/lib.rs";
+/// How big `aoc gen` makes an input, in lines or items, unless given `--size`.
+const DEFAULT_GEN_SIZE: usize = 10;
+/// How much slower than its baseline a step can get before `bench --compare` flags it.
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+enum Target {
+    All,
+    Day(u8),
+}
+
+struct RunArgs {
+    target: Target,
+    part: Option<u8>,
+    input: Option<String>,
+    /// Where to write the Markdown report, with the JSON one next to it.
+    report: Option<String>,
+    /// How many days to run at once.
+    jobs: NonZeroUsize,
+    /// Whether to read the input a record at a time instead of all at once.
+    stream: bool,
+}
+
+struct BenchArgs {
+    target: Target,
+    input: Option<String>,
+    config: BenchConfig,
+    json: String,
+    compare: bool,
+    threshold_percent: f64,
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("invalid day `{arg}`"))
+}
+
+fn parse_part(arg: &str) -> Result<u8, String> {
+    arg.parse()
+        .ok()
+        .filter(|part| (1..=2).contains(part))
+        .ok_or_else(|| format!("invalid part `{arg}`"))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut positional = Vec::new();
+    let mut input = None;
+    let mut report = None;
+    let mut jobs = pool::default_jobs();
+    let mut stream = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stream" => stream = true,
+            "--input" => {
+                let path = args.next().ok_or("missing value for `--input`")?;
+                input = Some(path.clone());
+            }
+            "--report" => {
+                let path = args.next().ok_or("missing value for `--report`")?;
+                report = Some(path.clone());
+            }
+            "--jobs" => {
+                jobs = NonZeroUsize::new(parse_count(arg, args.next())? as usize)
+                    .ok_or("`--jobs` must be at least 1")?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let (target, part) = match positional.as_slice() {
+        ["all"] => (Target::All, None),
+        ["all", _] => return Err("`run all` does not take a part".to_string()),
+        [day] => (Target::Day(parse_day(day)?), None),
+        [day, part] => (Target::Day(parse_day(day)?), Some(parse_part(part)?)),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    if input.is_some() && matches!(target, Target::All) {
+        return Err("`--input` can only be used with a single day".to_string());
+    }
+    if stream && (matches!(target, Target::All) || report.is_some()) {
+        return Err("`--stream` can only be used with a single day and no report".to_string());
+    }
+
+    Ok(RunArgs {
+        target,
+        part,
+        input,
+        report,
+        jobs,
+        stream,
+    })
+}
+
+fn parse_count(flag: &str, value: Option<&String>) -> Result<u32, String> {
+    let value = value.ok_or_else(|| format!("missing value for `{flag}`"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for `{flag}`"))
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut positional = Vec::new();
+    let mut input = None;
+    let mut config = BenchConfig::default();
+    let mut json = BENCH_JSON_PATH.to_string();
+    let mut compare = false;
+    let mut threshold_percent = DEFAULT_THRESHOLD_PERCENT;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let path = args.next().ok_or("missing value for `--input`")?;
+                input = Some(path.clone());
+            }
+            "--json" => {
+                let path = args.next().ok_or("missing value for `--json`")?;
+                json = path.clone();
+            }
+            "--runs" => config.runs = parse_count(arg, args.next())?,
+            "--warmup" => config.warmup = parse_count(arg, args.next())?,
+            "--compare" => compare = true,
+            "--threshold" => {
+                let value = args.next().ok_or("missing value for `--threshold`")?;
+                threshold_percent = value
+                    .parse()
+                    .ok()
+                    .filter(|percent: &f64| *percent >= 0.0)
+                    .ok_or_else(|| format!("invalid value `{value}` for `--threshold`"))?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let target = match positional.as_slice() {
+        ["all"] => Target::All,
+        [day] => Target::Day(parse_day(day)?),
+        _ => return Err(USAGE.to_string()),
+    };
+
+    if input.is_some() && matches!(target, Target::All) {
+        return Err("`--input` can only be used with a single day".to_string());
+    }
+
+    Ok(BenchArgs {
+        target,
+        input,
+        config,
+        json,
+        compare,
+        threshold_percent,
+    })
+}
+
+struct BatchArgs {
+    day: u8,
+    part: Option<u8>,
+    files: Vec<String>,
+    jobs: NonZeroUsize,
+}
+
+fn parse_batch_args(args: &[String]) -> Result<BatchArgs, String> {
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut jobs = pool::default_jobs();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = Some(parse_part(
+                    args.next().ok_or("missing value for `--part`")?,
+                )?)
+            }
+            "--jobs" => {
+                jobs = NonZeroUsize::new(parse_count(arg, args.next())? as usize)
+                    .ok_or("`--jobs` must be at least 1")?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    match positional.split_first() {
+        Some((day, files)) if !files.is_empty() => Ok(BatchArgs {
+            day: parse_day(day)?,
+            part,
+            files: files.to_vec(),
+            jobs,
+        }),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_new_args(args: &[String]) -> Result<u8, String> {
+    match args {
+        [day] => parse_day(day),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+struct GenArgs {
+    day: u8,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_gen_args(args: &[String]) -> Result<GenArgs, String> {
+    let mut positional = Vec::new();
+    let (mut size, mut seed) = (DEFAULT_GEN_SIZE, 0);
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => size = parse_count(arg, args.next())? as usize,
+            "--seed" => {
+                let value = args.next().ok_or("missing value for `--seed`")?;
+                seed = value
+                    .parse()
+                    .map_err(|_| format!("invalid value `{value}` for `--seed`"))?;
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    match positional.as_slice() {
+        [day] => Ok(GenArgs {
+            day: parse_day(day)?,
+            size,
+            seed,
+        }),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+enum InputCommand {
+    Add { day: u8, name: String, force: bool },
+    List(Target),
+    Show { day: u8, name: String },
+}
+
+fn parse_input_args(args: &[String]) -> Result<InputCommand, String> {
+    let mut positional = Vec::new();
+    let (mut sample, mut name, mut force) = (false, None, false);
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--sample" => sample = true,
+            "--name" => name = Some(args.next().ok_or("missing value for `--name`")?.as_str()),
+            "--force" => force = true,
+            flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+            _ => positional.push(arg.as_str()),
+        }
+    }
+
+    let named = sample || name.is_some();
+    match positional.as_slice() {
+        ["add", day] => Ok(InputCommand::Add {
+            day: parse_day(day)?,
+            name: store::input_name(sample, name)?,
+            force,
+        }),
+        ["show", day] if !force => Ok(InputCommand::Show {
+            day: parse_day(day)?,
+            name: store::input_name(sample, name)?,
+        }),
+        ["list"] if !named && !force => Ok(InputCommand::List(Target::All)),
+        ["list", "all"] if !named && !force => Ok(InputCommand::List(Target::All)),
+        ["list", day] if !named && !force => Ok(InputCommand::List(Target::Day(parse_day(day)?))),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_verify_args(args: &[String]) -> Result<Target, String> {
+    match args {
+        [] => Ok(Target::All),
+        [all] if all == "all" => Ok(Target::All),
+        [day] => Ok(Target::Day(parse_day(day)?)),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+enum Failure {
+    /// Bad arguments, or an input that could not be read.
+    Usage(String),
+    /// A day rejected its input.
+    Rejected { path: String, error: AocError },
+    /// `verify` found answers that don't match the recorded ones.
+    Mismatches(usize),
+    /// `bench --compare` found steps that got slower.
+    Regressions(usize),
+    /// `batch` found files a day failed on.
+    FailedFiles(usize),
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Failure::Usage(message)
+    }
+}
+
+impl Failure {
+    fn exit_code(&self) -> ExitCode {
+        match self {
+            Failure::Usage(_)
+            | Failure::Mismatches(_)
+            | Failure::Regressions(_)
+            | Failure::FailedFiles(_) => ExitCode::from(1),
+            Failure::Rejected { .. } => ExitCode::from(2),
+        }
+    }
+}
+
+fn default_input_path(day: u8) -> String {
+    format!("inputs/{day:02}.in")
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("error reading input from stdin: {e}"))?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("error reading `{path}`: {e}"))
+    }
+}
+
+fn select(target: &Target) -> Result<Vec<&'static Solver>, String> {
+    match *target {
+        Target::All => Ok(days::SOLVERS.iter().collect()),
+        Target::Day(day) => Ok(vec![
+            days::find(day).ok_or_else(|| format!("day {day:02} is not solved"))?,
+        ]),
+    }
+}
+
+fn selected_parts(args: &RunArgs, solver: &Solver) -> Vec<u8> {
+    match args.part {
+        Some(part) => vec![part],
+        None => (1..=solver.parts).collect(),
+    }
+}
+
+/// The answers of one day, solved by a worker and waiting to be printed.
+struct Solved {
+    day: u8,
+    path: String,
+    parts: Vec<u8>,
+    solutions: Solutions,
+}
+
+fn run(args: &RunArgs) -> Result<(), Failure> {
+    let selected = select(&args.target)?;
+
+    if let (Target::Day(day), Some(part)) = (&args.target, args.part)
+        && !selected[0].has_part(part)
+    {
+        return Err(Failure::Usage(format!("day {day:02} has no part {part}")));
+    }
+
+    if let Some(path) = &args.report {
+        return run_report(args, &selected, path);
+    }
+
+    // Only label the answers when there is more than one of them
+    let labelled = selected.len() > 1 || args.part.is_none();
+
+    let solve_day = |solver: &&Solver| -> Result<Solved, Failure> {
+        let parts = selected_parts(args, solver);
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| default_input_path(solver.day));
+        if args.stream {
+            return solve_streamed(solver, path, parts);
+        }
+        let input = read_input(&path)?;
+
+        match (solver.solve)(&input, &parts) {
+            Ok(solutions) => Ok(Solved {
+                day: solver.day,
+                path,
+                parts,
+                solutions,
+            }),
+            Err(error) => Err(Failure::Rejected { path, error }),
+        }
+    };
+
+    // Days run side by side, but are printed in order and stop at the first failure
+    pool::run_ordered(&selected, args.jobs, solve_day, |solved| {
+        let Solved {
+            day,
+            path,
+            parts,
+            solutions,
+        } = solved?;
+
+        for (part, solution) in parts.iter().zip(solutions) {
+            let solution = solution.map_err(|error| Failure::Rejected {
+                path: path.clone(),
+                error,
+            })?;
+            if labelled {
+                println!("day {day:02} part {part}: {solution}");
+            } else {
+                println!("{solution}");
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Solves `parts` of a day reading its input a record at a time, for inputs too big for memory.
+fn solve_streamed(solver: &Solver, path: String, parts: Vec<u8>) -> Result<Solved, Failure> {
+    let stream = solver.stream.ok_or_else(|| {
+        format!(
+            "day {:02} can't be streamed, it needs its whole input at once",
+            solver.day
+        )
+    })?;
+
+    let result = if path == "-" {
+        stream(&mut io::stdin().lock(), &parts)
+    } else {
+        let file = fs::File::open(&path).map_err(|e| format!("error reading `{path}`: {e}"))?;
+        stream(&mut io::BufReader::new(file), &parts)
+    };
+
+    match result {
+        Ok(solutions) => Ok(Solved {
+            day: solver.day,
+            path,
+            parts,
+            solutions,
+        }),
+        Err(error) => Err(Failure::Rejected { path, error }),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".to_string())
+}
+
+/// Solves `parts` of `solver`, turning errors and panics into outcomes.
+fn solve_caught(solver: &Solver, input: &str, parts: &[u8]) -> Vec<Outcome> {
+    match panic::catch_unwind(|| (solver.solve)(input, parts)) {
+        Ok(Ok(solutions)) => solutions
+            .into_iter()
+            .map(|solution| match solution {
+                Ok(answer) => Outcome::Solved(answer),
+                Err(error) => Outcome::Failed(error.to_string()),
+            })
+            .collect(),
+        Ok(Err(error)) => vec![Outcome::Failed(error.to_string()); parts.len()],
+        Err(payload) => vec![Outcome::Panicked(panic_message(&*payload)); parts.len()],
+    }
+}
+
+/// Runs and times each selected part of `solver` on its own, recording errors and panics.
+fn report_day(args: &RunArgs, answers: &Answers, store: &Store, solver: &Solver) -> Vec<Row> {
+    let mut inputs: BTreeMap<String, Result<String, String>> = BTreeMap::new();
+
+    selected_parts(args, solver)
+        .into_iter()
+        .map(|part| {
+            let name = answers.input_for(solver.day, PERSONAL_INPUT, part);
+            let (input_path, expected) = match &args.input {
+                Some(path) => (path.clone(), None),
+                None => (
+                    format!("inputs/{}", answers::input_file(solver.day, &name)),
+                    answers.get(solver.day, &name, part),
+                ),
+            };
+
+            let input = inputs
+                .entry(input_path.clone())
+                .or_insert_with(|| read_input(&input_path));
+            if let (Ok(input), Some(Some(_))) = (&input, expected.map(Expected::answer)) {
+                store.warn_if_changed(solver.day, &name, input);
+            }
+            let (outcome, time) = match input {
+                Err(_) if input_path != "-" && !Path::new(&input_path).exists() => {
+                    (Outcome::MissingInput, None)
+                }
+                Err(error) => (Outcome::Failed(error.clone()), None),
+                Ok(input) => {
+                    let start = Instant::now();
+                    let outcome = solve_caught(solver, input, &[part]).remove(0);
+                    (outcome, Some(start.elapsed()))
+                }
+            };
+
+            Row {
+                day: solver.day,
+                part,
+                input: input_path,
+                status: Status::of(&outcome, expected),
+                outcome,
+                time,
+            }
+        })
+        .collect()
+}
+
+/// Runs every selected part on its own, on the personal input unless `--input` says otherwise,
+/// and writes the results as a Markdown table to `path` and as JSON next to it. Errors and
+/// panics are recorded instead of stopping the run.
+fn run_report(args: &RunArgs, selected: &[&Solver], path: &str) -> Result<(), Failure> {
+    let markdown_path = Path::new(path);
+    let json_path = markdown_path.with_extension("json");
+    if json_path == markdown_path {
+        return Err(
+            "`--report` takes the Markdown file, the JSON one is written next to it"
+                .to_string()
+                .into(),
+        );
+    }
+
+    let answers = read_answers()?;
+    let store = read_store()?;
+    let mut rows = Vec::new();
+
+    // Panics end up in the report, so the default hook shouldn't print them as well
+    panic::set_hook(Box::new(|_| {}));
+
+    pool::run_ordered(
+        selected,
+        args.jobs,
+        |solver| report_day(args, &answers, &store, solver),
+        |day_rows| {
+            for row in &day_rows {
+                let shown = match &row.outcome {
+                    Outcome::Solved(answer) => answer.to_string(),
+                    Outcome::Failed(error) => format!("error, {error}"),
+                    Outcome::Panicked(message) => format!("panicked, {message}"),
+                    Outcome::MissingInput => format!("missing `{}`", row.input),
+                };
+                match row.status {
+                    Some(status) => {
+                        println!("day {:02} part {}: {shown} ({status})", row.day, row.part)
+                    }
+                    None => println!("day {:02} part {}: {shown}", row.day, row.part),
+                }
+            }
+            rows.extend(day_rows);
+            Ok::<_, Failure>(())
+        },
+    )?;
+
+    drop(panic::take_hook());
+
+    write_file(path, &report::markdown(&rows))?;
+    let json_path = json_path.to_string_lossy();
+    write_file(&json_path, &report::to_json(&rows))?;
+    println!("wrote {path} and {json_path}");
+
+    match rows
+        .iter()
+        .filter(|row| row.status == Some(Status::Fail))
+        .count()
+    {
+        0 => Ok(()),
+        failed => Err(Failure::Mismatches(failed)),
+    }
+}
+
+/// Reads both files of the store, either of which may be missing.
+fn read_store() -> Result<Store, String> {
+    let mut store = Store::default();
+
+    for file in store::STORE_FILES {
+        let path = format!("{INPUTS_DIR}/{file}");
+        match fs::read_to_string(&path) {
+            Ok(text) => store.merge(Store::parse(&text).map_err(|e| format!("`{path}`: {e}"))?),
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(format!("error reading `{path}`: {e}")),
+        }
+    }
+
+    Ok(store)
+}
+
+/// Runs one day on every file and prints a table of the answers, one row per file, followed by
+/// the errors of the files it failed on.
+fn batch(args: &BatchArgs) -> Result<(), Failure> {
+    let solver =
+        days::find(args.day).ok_or_else(|| format!("day {:02} is not solved", args.day))?;
+    let parts: Vec<u8> = match args.part {
+        Some(part) if !solver.has_part(part) => {
+            return Err(format!("day {:02} has no part {part}", args.day).into());
+        }
+        Some(part) => vec![part],
+        None => (1..=solver.parts).collect(),
+    };
+
+    // Panics are shown with the other failures, so the default hook shouldn't print them too
+    panic::set_hook(Box::new(|_| {}));
+    let mut results = Vec::new();
+    pool::run_ordered(
+        &args.files,
+        args.jobs,
+        |path| match read_input(path) {
+            Ok(input) => solve_caught(solver, &input, &parts),
+            Err(error) => vec![Outcome::Failed(error); parts.len()],
+        },
+        |outcomes| {
+            results.push(outcomes);
+            Ok::<_, Failure>(())
+        },
+    )?;
+    drop(panic::take_hook());
+
+    let cell = |outcome: &Outcome| match outcome {
+        Outcome::Solved(answer) => answer.to_string(),
+        Outcome::Failed(_) | Outcome::MissingInput => "FAILED".to_string(),
+        Outcome::Panicked(_) => "PANICKED".to_string(),
+    };
+    let file_width = args.files.iter().map(String::len).max().unwrap_or(0).max(4);
+    let part_widths: Vec<usize> = (0..parts.len())
+        .map(|i| {
+            results
+                .iter()
+                .map(|outcomes| cell(&outcomes[i]).len())
+                .max()
+                .unwrap_or(0)
+                .max(6)
+        })
+        .collect();
+
+    let mut header = format!("{:<file_width$}", "file");
+    for (part, width) in parts.iter().zip(&part_widths) {
+        header += &format!("  {:<width$}", format!("part {part}"));
+    }
+    println!("{}", header.trim_end());
+
+    let mut failures = Vec::new();
+    for (path, outcomes) in args.files.iter().zip(&results) {
+        let mut row = format!("{path:<file_width$}");
+        for (outcome, width) in outcomes.iter().zip(&part_widths) {
+            row += &format!("  {:<width$}", cell(outcome));
+        }
+        println!("{}", row.trim_end());
+
+        let mut messages: Vec<String> = parts
+            .iter()
+            .zip(outcomes)
+            .filter_map(|(part, outcome)| match outcome {
+                Outcome::Solved(_) => None,
+                Outcome::Failed(error) => Some(error.clone()),
+                Outcome::Panicked(message) => Some(format!("part {part} panicked: {message}")),
+                Outcome::MissingInput => Some(format!("missing `{path}`")),
+            })
+            .collect();
+        // A parse error fails every part the same way
+        messages.dedup();
+        if !messages.is_empty() {
+            failures.push((path, messages));
+        }
+    }
+
+    for (path, messages) in &failures {
+        for message in messages {
+            println!("\n{path}: {message}");
+        }
+    }
+    let files = match args.files.len() {
+        1 => "1 file".to_string(),
+        n => format!("{n} files"),
+    };
+    println!("\n{files}, {} failed", failures.len());
+
+    match failures.len() {
+        0 => Ok(()),
+        failed => Err(Failure::FailedFiles(failed)),
+    }
+}
+
+fn read_answers() -> Result<Answers, String> {
+    let text = fs::read_to_string(ANSWERS_PATH)
+        .map_err(|e| format!("error reading `{ANSWERS_PATH}`: {e}"))?;
+    Answers::parse(&text).map_err(|e| format!("`{ANSWERS_PATH}`: {e}"))
+}
+
+/// Checks the selected days on every input with a recorded answer, plus the sample and the
+/// personal input, and reports each part as passing, failing or unknown.
+fn verify(target: &Target) -> Result<(), Failure> {
+    let selected = select(target)?;
+    let answers = read_answers()?;
+    let store = read_store()?;
+    let (mut passed, mut failed, mut known_failures, mut unknown, mut missing) = (0, 0, 0, 0, 0);
+
+    // Panics are reported as failures, so the default hook shouldn't print them as well
+    panic::set_hook(Box::new(|_| {}));
+
+    for solver in selected {
+        // The parts to check on each input, with the personal input sorted last
+        let mut inputs: BTreeMap<(bool, String), Vec<u8>> = BTreeMap::new();
+        for part in 1..=solver.parts {
+            for name in answers.inputs(solver.day, part) {
+                inputs
+                    .entry((name == PERSONAL_INPUT, name))
+                    .or_default()
+                    .push(part);
+            }
+        }
+
+        for ((_, name), parts) in inputs {
+            let path = format!("inputs/{}", answers::input_file(solver.day, &name));
+            let label = |part| format!("day {:02} part {part} {name}", solver.day);
+
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(e) if e.kind() == ErrorKind::NotFound => {
+                    for &part in &parts {
+                        println!("{}: missing `{path}`", label(part));
+                    }
+                    missing += parts.len();
+                    continue;
+                }
+                Err(e) => return Err(format!("error reading `{path}`: {e}").into()),
+            };
+            if parts.iter().any(|&part| {
+                answers
+                    .get(solver.day, &name, part)
+                    .and_then(Expected::answer)
+                    .is_some()
+            }) {
+                store.warn_if_changed(solver.day, &name, &input);
+            }
+
+            let outcomes = solve_caught(solver, &input, &parts);
+            for (&part, outcome) in parts.iter().zip(&outcomes) {
+                let expected = answers.get(solver.day, &name, part);
+                let got = match outcome {
+                    Outcome::Solved(answer) => answer.to_string(),
+                    Outcome::Failed(error) => error.clone(),
+                    Outcome::Panicked(message) => format!("panicked, {message}"),
+                    Outcome::MissingInput => unreachable!("the input was read"),
+                };
+
+                match (Status::of(outcome, expected), expected) {
+                    (Some(Status::Pass), _) => {
+                        passed += 1;
+                        println!("{}: pass", label(part));
+                    }
+                    (
+                        Some(Status::KnownFailure),
+                        Some(Expected::KnownFailure { answer, reason }),
+                    ) => {
+                        known_failures += 1;
+                        println!(
+                            "{}: known failure, expected {answer}, got {got}: {reason}",
+                            label(part)
+                        );
+                    }
+                    (Some(Status::Fail), Some(Expected::KnownFailure { .. }))
+                        if matches!(outcome, Outcome::Solved(_)) =>
+                    {
+                        failed += 1;
+                        println!(
+                            "{}: FAIL, right now, take its `fails` marker out of `answers.toml`",
+                            label(part)
+                        );
+                    }
+                    (Some(Status::Fail), Some(Expected::Known(expected)))
+                        if matches!(outcome, Outcome::Solved(_)) =>
+                    {
+                        failed += 1;
+                        println!("{}: FAIL, expected {expected}, got {got}", label(part));
+                    }
+                    (Some(Status::Unknown), _) => {
+                        unknown += 1;
+                        println!("{}: unknown, got {got}", label(part));
+                    }
+                    _ => {
+                        failed += 1;
+                        println!("{}: FAIL, {got}", label(part));
+                    }
+                }
+            }
+        }
+    }
+
+    drop(panic::take_hook());
+
+    println!(
+        "{passed} passed, {failed} failed, {known_failures} known failures, {unknown} unknown, \
+         {missing} missing"
+    );
+
+    if failed > 0 {
+        Err(Failure::Mismatches(failed))
+    } else {
+        Ok(())
+    }
+}
+
+/// Times every selected day, printing a table as it goes and writing all the timings as JSON.
+fn bench(args: &BenchArgs) -> Result<(), Failure> {
+    let selected = select(&args.target)?;
+    let mut benches = Vec::new();
+
+    if memory::ENABLED {
+        println!(
+            "{:<4} {:<7} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "day", "step", "min", "median", "max", "allocs", "allocated", "peak"
+        );
+    } else {
+        println!(
+            "{:<4} {:<7} {:>10} {:>10} {:>10}",
+            "day", "step", "min", "median", "max"
+        );
+    }
+
+    for solver in selected {
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| default_input_path(solver.day));
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(message) if matches!(args.target, Target::All) => {
+                eprintln!("skipping day {:02}: {message}", solver.day);
+                continue;
+            }
+            Err(message) => return Err(message.into()),
+        };
+
+        let parts: Vec<u8> = (1..=solver.parts).collect();
+        let timings =
+            (solver.bench)(&input, &parts, &args.config).map_err(|error| Failure::Rejected {
+                path: path.clone(),
+                error,
+            })?;
+
+        for (step, stats) in timings.steps() {
+            let allocations = match timings.allocations(step) {
+                Some(counted) => format!(
+                    " {:>10} {:>10} {:>10}",
+                    counted.count,
+                    Bytes(counted.bytes),
+                    Bytes(counted.peak)
+                ),
+                None => String::new(),
+            };
+            println!(
+                "{:<4} {:<7} {:>10.1?} {:>10.1?} {:>10.1?}{allocations}",
+                format!("{:02}", solver.day),
+                step,
+                stats.min,
+                stats.median,
+                stats.max
+            );
+        }
+
+        benches.push(DayBench {
+            day: solver.day,
+            input: path,
+            timings,
+        });
+    }
+
+    fs::write(&args.json, bench::to_json(&args.config, &benches))
+        .map_err(|e| format!("error writing `{}`: {e}", args.json))?;
+    println!("timings written to `{}`", args.json);
+
+    let records = history::records(
+        &history::utc_timestamp(SystemTime::now()),
+        &git_revision(),
+        &benches,
+    );
+    let regressions = if args.compare {
+        compare_with_history(&records, args.threshold_percent)?
+    } else {
+        0
+    };
+
+    history::append(Path::new(BENCH_HISTORY_PATH), &records)
+        .map_err(|e| format!("error writing `{BENCH_HISTORY_PATH}`: {e}"))?;
+
+    if regressions > 0 {
+        return Err(Failure::Regressions(regressions));
+    }
+
+    Ok(())
+}
+
+/// The short hash of `HEAD`, marked `-dirty` when the work tree has changes.
+fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain"]),
+    ) {
+        (Some(hash), Some(changes)) if !changes.is_empty() => format!("{hash}-dirty"),
+        (Some(hash), _) => hash,
+        (None, _) => "unknown".to_string(),
+    }
+}
+
+/// Prints how each step compares with its latest earlier run and returns how many got slower
+/// than `threshold_percent`.
+fn compare_with_history(
+    records: &[history::Record],
+    threshold_percent: f64,
+) -> Result<usize, String> {
+    let earlier = match fs::read_to_string(BENCH_HISTORY_PATH) {
+        Ok(text) => history::parse(&text).map_err(|e| format!("`{BENCH_HISTORY_PATH}`: {e}"))?,
+        Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("error reading `{BENCH_HISTORY_PATH}`: {e}")),
+    };
+
+    let comparisons = history::compare(&earlier, records);
+    if comparisons.is_empty() {
+        println!(
+            "no earlier run of this {} build with features `{}` to compare with",
+            history::profile(),
+            history::features()
+        );
+        return Ok(0);
+    }
+
+    println!();
+    println!(
+        "{:<4} {:<7} {:>10} {:>10} {:>8}  baseline",
+        "day", "step", "before", "after", "change"
+    );
+
+    let mut regressions = 0;
+    for comparison in &comparisons {
+        let slowdown = comparison.slowdown_percent();
+        let flag = if slowdown.is_some_and(|slowdown| slowdown > threshold_percent) {
+            regressions += 1;
+            "  SLOWER"
+        } else {
+            ""
+        };
+
+        println!(
+            "{:<4} {:<7} {:>10.1?} {:>10.1?} {:>8}  {} {}{flag}",
+            format!("{:02}", comparison.current.day),
+            comparison.current.step,
+            comparison.baseline.stats.median,
+            comparison.current.stats.median,
+            slowdown.map_or_else(|| "-".to_string(), |slowdown| format!("{slowdown:+.1}%")),
+            comparison.baseline.revision,
+            comparison.baseline.timestamp
+        );
+    }
+
+    Ok(regressions)
+}
+
+fn read_file(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("error reading `{path}`: {e}"))
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("error writing `{path}`: {e}"))
+}
+
+/// Prints a random input for a day, the same for the same seed.
+fn generate(args: &GenArgs) -> Result<(), Failure> {
+    let solver =
+        days::find(args.day).ok_or_else(|| format!("day {:02} is not solved", args.day))?;
+    let input = (solver.generate)(&mut Rng::new(args.seed), args.size);
+
+    io::stdout()
+        .lock()
+        .write_all(input.as_bytes())
+        .map_err(|e| format!("could not write the input: {e}").into())
+}
+
+/// Starts `day` from the template: creates its module, registers it with the runner, records
+/// its answers as unknown and creates its empty inputs. Refuses to touch a day that exists.
+fn new_day(day: u8) -> Result<(), Failure> {
+    let module_path = format!("src/days/day{day:02}.rs");
+    if Path::new(&module_path).exists() {
+        return Err(format!("`{module_path}` already exists").into());
+    }
+
+    // Every edit is worked out before anything is written, so a refusal leaves no trace
+    let module = scaffold::day_module(&read_file(TEMPLATE_PATH)?, day);
+    let days_mod = scaffold::register(&read_file(DAYS_MOD_PATH)?, day)?;
+    let lib = scaffold::export(&read_file(LIB_PATH)?, day)?;
+    let answers = scaffold::add_answers(&read_file(ANSWERS_PATH)?, day)?;
+
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&module_path)
+        .and_then(|mut file| file.write_all(module.as_bytes()))
+        .map_err(|e| format!("error writing `{module_path}`: {e}"))?;
+    println!("created {module_path}");
+    write_file(DAYS_MOD_PATH, &days_mod)?;
+    println!("registered day {day:02} in {DAYS_MOD_PATH}");
+    write_file(LIB_PATH, &lib)?;
+    println!("exported day {day:02} from {LIB_PATH}");
+    write_file(ANSWERS_PATH, &answers)?;
+    println!("added unknown answers to {ANSWERS_PATH}");
+
+    for path in [
+        format!("inputs/{}", answers::input_file(day, answers::SAMPLE)),
+        default_input_path(day),
+    ] {
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(_) => println!("created {path}"),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => println!("kept existing {path}"),
+            Err(e) => return Err(format!("error creating `{path}`: {e}").into()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the input on stdin to `inputs/` and records its checksum. Refuses to replace a different
+/// file under the same name unless `force` is set.
+fn add_input(day: u8, name: &str, force: bool) -> Result<(), Failure> {
+    let input = read_input("-")?;
+    if input.trim().is_empty() {
+        return Err("no input on stdin".to_string().into());
+    }
+
+    let path = format!("{INPUTS_DIR}/{}", answers::input_file(day, name));
+    let mut store = read_store()?;
+    match fs::read_to_string(&path) {
+        Ok(existing) if store::checksum(&existing) == store::checksum(&input) => {}
+        Ok(_) if !force => {
+            return Err(format!(
+                "`{path}` already exists with a different input, pass `--force` to replace it"
+            )
+            .into());
+        }
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => return Err(format!("error reading `{path}`: {e}").into()),
+    }
+
+    write_file(&path, &input)?;
+    store.record(day, name, &input);
+    // Both files are written, which also moves personal checksums that older versions kept in
+    // the shared file to the local one
+    for file in store::STORE_FILES {
+        write_file(&format!("{INPUTS_DIR}/{file}"), &store.to_tsv(file))?;
+    }
+    println!(
+        "added {path}, sha256 {}",
+        store.get(day, name).unwrap_or_default()
+    );
+
+    Ok(())
+}
+
+/// Lists the inputs in `inputs/` and those in the store, with whether each still matches its
+/// checksum.
+fn list_inputs(target: &Target) -> Result<(), Failure> {
+    let store = read_store()?;
+    let mut names = BTreeSet::new();
+
+    let entries =
+        fs::read_dir(INPUTS_DIR).map_err(|e| format!("error reading `{INPUTS_DIR}`: {e}"))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("error reading `{INPUTS_DIR}`: {e}"))?;
+        if let Some((day, name)) = entry.file_name().to_str().and_then(store::parse_file_name) {
+            names.insert((day, !answers::is_sample(&name), name));
+        }
+    }
+    for (day, name, _) in store.entries() {
+        names.insert((day, !answers::is_sample(name), name.to_string()));
+    }
+
+    println!(
+        "{:<4} {:<18} {:<30} {:>8}  status",
+        "day", "input", "file", "bytes"
+    );
+    for (day, _, name) in names {
+        if matches!(target, Target::Day(selected) if *selected != day) {
+            continue;
+        }
+
+        let path = format!("{INPUTS_DIR}/{}", answers::input_file(day, &name));
+        let (bytes, status) = match fs::read_to_string(&path) {
+            Ok(input) => (
+                input.len().to_string(),
+                match store.check(day, &name, &input) {
+                    Check::Unchanged => "ok",
+                    Check::Changed => "CHANGED",
+                    Check::Unrecorded => "not added",
+                },
+            ),
+            Err(e) if e.kind() == ErrorKind::NotFound => ("-".to_string(), "missing"),
+            Err(e) => return Err(format!("error reading `{path}`: {e}").into()),
+        };
+        println!("{day:02}   {name:<18} {path:<30} {bytes:>8}  {status}");
+    }
+
+    Ok(())
+}
+
+fn input(command: InputCommand) -> Result<(), Failure> {
+    match command {
+        InputCommand::Add { day, name, force } => add_input(day, &name, force),
+        InputCommand::List(target) => list_inputs(&target),
+        InputCommand::Show { day, name } => {
+            let path = format!("{INPUTS_DIR}/{}", answers::input_file(day, &name));
+            print!("{}", read_input(&path)?);
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(message) = log::init_from_env() {
+        eprintln!("error: {message}");
+        return ExitCode::FAILURE;
+    }
+    // `--verbose` goes with any command
+    let verbose = args.iter().filter(|arg| *arg == "--verbose").count();
+    args.retain(|arg| arg != "--verbose");
+    match verbose {
+        0 => {}
+        1 => log::set_level(log::level().max(Level::Debug)),
+        _ => log::set_level(Level::Trace),
+    }
+
+    let result = match args.split_first() {
+        Some((command, rest)) if command == "run" => parse_run_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(|a| run(&a)),
+        Some((command, rest)) if command == "verify" => parse_verify_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(|target| verify(&target)),
+        Some((command, rest)) if command == "input" => parse_input_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(input),
+        Some((command, rest)) if command == "batch" => parse_batch_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(|a| batch(&a)),
+        Some((command, rest)) if command == "new" => parse_new_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(new_day),
+        Some((command, rest)) if command == "gen" => parse_gen_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(|a| generate(&a)),
+        Some((command, rest)) if command == "bench" => parse_bench_args(rest)
+            .map_err(Failure::Usage)
+            .and_then(|a| bench(&a)),
+        _ => Err(Failure::Usage(USAGE.to_string())),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            match &failure {
+                Failure::Usage(message) => eprintln!("error: {message}"),
+                Failure::Rejected { path, error } if path == "-" => {
+                    eprintln!("error: stdin: {error}")
+                }
+                Failure::Rejected { path, error } => eprintln!("error: `{path}`: {error}"),
+                Failure::Mismatches(1) => eprintln!("error: 1 answer did not match"),
+                Failure::Mismatches(n) => eprintln!("error: {n} answers did not match"),
+                Failure::Regressions(1) => eprintln!("error: 1 step got slower"),
+                Failure::Regressions(n) => eprintln!("error: {n} steps got slower"),
+                Failure::FailedFiles(1) => eprintln!("error: failed on 1 file"),
+                Failure::FailedFiles(n) => eprintln!("error: failed on {n} files"),
+            }
+            failure.exit_code()
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Cross-checks a day's solver against a slow reference solution on random small inputs, and
+//! shrinks any input they disagree on to a small one that shows the difference.
+
+use crate::generate::Generate;
+use crate::parse::normalize;
+use crate::rng::Rng;
+use crate::{Answer, AocError};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A day with a second, obviously correct way to get its answers, however slow. Its tests check
+/// the two against each other when `reference` is listed in its `test_with_files!`.
+pub trait Reference: Generate {
+    /// The answer to `part`, worked out the slow way from the same parsed input.
+    fn reference(parsed: &Self::Parsed<'_>, part: u8) -> Result<Answer, AocError>;
+
+    /// Whether a parsed input is one the puzzle could give, so that shrinking doesn't stray into
+    /// inputs the solver never promised to handle.
+    fn is_valid(_parsed: &Self::Parsed<'_>) -> bool {
+        true
+    }
+
+    /// Smaller versions of `input` to try while shrinking, largest first. By default, the input
+    /// without one or more of its lines.
+    fn shrink(input: &str) -> Vec<String> {
+        let lines: Vec<&str> = input.lines().collect();
+        without_chunks(&lines)
+            .into_iter()
+            .map(|lines| lines.join("\n") + "\n")
+            .collect()
+    }
+}
+
+/// `items` without a chunk of them, for every chunk of every size from half of them down to
+/// one, largest first.
+pub fn without_chunks<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut smaller = Vec::new();
+    let mut chunk = items.len() / 2;
+
+    while chunk > 0 {
+        for start in (0..items.len()).step_by(chunk) {
+            let mut rest = items[..start].to_vec();
+            rest.extend_from_slice(&items[(start + chunk).min(items.len())..]);
+            smaller.push(rest);
+        }
+        chunk /= 2;
+    }
+
+    smaller
+}
+
+/// How many inputs to try and how big to make them.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub cases: u64,
+    /// The size of the last inputs, with the first ones starting at 1.
+    pub max_size: usize,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cases: 300,
+            max_size: 12,
+            seed: 2025,
+        }
+    }
+}
+
+/// What a solver gave for one part: an answer, an error, or a panic.
+type Outcome = Result<Answer, String>;
+
+fn caught(run: impl FnOnce() -> Result<Answer, AocError>) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(run)) {
+        Ok(answer) => answer.map_err(|error| error.to_string()),
+        Err(payload) => Err(match payload.downcast_ref::<&str>() {
+            Some(message) => format!("panicked: {message}"),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => format!("panicked: {message}"),
+                None => "panicked".to_string(),
+            },
+        }),
+    }
+}
+
+/// Both outcomes of `part` on `input`, or `None` if the input doesn't parse or isn't valid.
+fn outcomes<S: Reference>(input: &str, part: u8) -> Option<(Outcome, Outcome)> {
+    let input = normalize(input);
+    let parsed = S::parse(&input).ok()?;
+    if !S::is_valid(&parsed) {
+        return None;
+    }
+
+    let fast = caught(|| match part {
+        1 => S::part1(&parsed),
+        _ => S::part2(&parsed),
+    });
+    let reference = caught(|| S::reference(&parsed, part));
+
+    Some((fast, reference))
+}
+
+/// An input the solver and the reference disagree on.
+#[derive(Debug)]
+pub struct Disagreement {
+    pub day: u8,
+    pub part: u8,
+    /// The seed of the input before shrinking.
+    pub seed: u64,
+    pub input: String,
+    pub fast: Outcome,
+    pub reference: Outcome,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let show = |outcome: &Outcome| match outcome {
+            Ok(answer) => answer.to_string(),
+            Err(error) => error.clone(),
+        };
+
+        write!(
+            f,
+            "day {:02} part {} disagrees with its reference (seed {}): solver gave {}, reference \
+             gave {} on\n{}",
+            self.day,
+            self.part,
+            self.seed,
+            show(&self.fast),
+            show(&self.reference),
+            self.input
+        )
+    }
+}
+
+/// Shrinks `input` while the two solutions still disagree on `part`.
+fn shrink<S: Reference>(mut input: String, part: u8) -> (String, Outcome, Outcome) {
+    let (mut fast, mut reference) = outcomes::<S>(&input, part).expect("input stopped parsing");
+
+    'smaller: loop {
+        for candidate in S::shrink(&input) {
+            if let Some((candidate_fast, candidate_reference)) = outcomes::<S>(&candidate, part)
+                && candidate_fast != candidate_reference
+            {
+                (input, fast, reference) = (candidate, candidate_fast, candidate_reference);
+                continue 'smaller;
+            }
+        }
+        return (input, fast, reference);
+    }
+}
+
+/// Runs both solutions of every part on `config.cases` random inputs, returning the first
+/// disagreement, shrunk.
+///
+/// # Panics
+///
+/// If the generator makes an input the day rejects.
+pub fn cross_check<S: Reference>(config: &Config) -> Result<(), Disagreement> {
+    // Panic messages go to the test's captured output, only shown if it fails
+    let result = (0..config.cases).try_for_each(|case| {
+        let seed = config.seed.wrapping_add(case);
+        let size = 1 + (case as usize * config.max_size) / config.cases.max(1) as usize;
+        let input = S::generate(&mut Rng::new(seed), size);
+
+        for part in 1..=S::PARTS {
+            let Some((fast, reference)) = outcomes::<S>(&input, part) else {
+                return Err(Err(input));
+            };
+            if fast != reference {
+                let (input, fast, reference) = shrink::<S>(input, part);
+                return Err(Ok(Disagreement {
+                    day: S::DAY,
+                    part,
+                    seed,
+                    input,
+                    fast,
+                    reference,
+                }));
+            }
+        }
+        Ok(())
+    });
+
+    match result {
+        Ok(()) => Ok(()),
+        Err(Ok(disagreement)) => Err(disagreement),
+        Err(Err(input)) => panic!(
+            "day {:02} generated an input it rejects or deems invalid:\n{input}",
+            S::DAY
+        ),
+    }
+}
+
+/// Cross-checks `S` with the default [`Config`], failing the test on a disagreement.
+pub fn assert_matches_reference<S: Reference>() {
+    if let Err(disagreement) = cross_check::<S>(&Config::default()) {
+        panic!("{disagreement}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::without_chunks;
+
+    #[test]
+    fn removes_chunks_largest_first() {
+        assert_eq!(
+            without_chunks(&[1, 2, 3, 4]),
+            [
+                vec![3, 4],
+                vec![1, 2],
+                vec![2, 3, 4],
+                vec![1, 3, 4],
+                vec![1, 2, 4],
+                vec![1, 2, 3]
+            ]
+        );
+        assert!(without_chunks::<u8>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::generate::Generate;
+use crate::parse::{expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::stream::Streaming;
+use crate::{Answer, AocError, Solution, solution_fns};
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::dec_int;
+use winnow::combinator::{alt, preceded};
+
+pub struct Day01;
+
+#[derive(Debug)]
+pub enum Rotation {
+    Left(i32),
+    Right(i32),
+}
+
+fn parse_rotation(input: &mut &str) -> Result<Rotation> {
+    alt((
+        preceded('L', dec_int).map(Rotation::Left),
+        preceded('R', dec_int).map(Rotation::Right),
+    ))
+    .context(expected("rotation such as `L68`"))
+    .parse_next(input)
+}
+
+fn parse_rotation_list(input: &mut &str) -> Result<Vec<Rotation>> {
+    lines(parse_rotation).parse_next(input)
+}
+
+/// The dial's angle after `rotation` from `angle`, and how many clicks of it landed on 0.
+pub fn apply_rotation(angle: i32, rotation: &Rotation) -> (i32, i32) {
+    let clicks = match rotation {
+        Rotation::Left(amount) if angle == 0 => (angle + amount) / 100,
+        Rotation::Left(amount) => ((100 - angle) + amount) / 100,
+        Rotation::Right(amount) => (angle + amount) / 100,
+    };
+
+    let new_angle = match rotation {
+        Rotation::Left(amount) => (angle - amount).rem_euclid(100),
+        Rotation::Right(amount) => (angle + amount).rem_euclid(100),
+    };
+
+    (new_angle, clicks)
+}
+
+impl Solution for Day01 {
+    type Parsed<'a> = Vec<Rotation>;
+
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Result<Vec<Rotation>, AocError> {
+        parse_input(parse_rotation_list, input)
+    }
+
+    fn part1(rotations: &Vec<Rotation>) -> Result<Answer, AocError> {
+        let mut initial_rotation = 50;
+        let mut password = 0;
+
+        for rot in rotations {
+            match rot {
+                Rotation::Left(amount) => {
+                    initial_rotation = (initial_rotation + 100 - amount) % 100
+                }
+                Rotation::Right(amount) => initial_rotation = (initial_rotation + amount) % 100,
+            }
+
+            if initial_rotation == 0 {
+                password += 1;
+            }
+        }
+
+        Ok(password.into())
+    }
+
+    fn part2(rotations: &Vec<Rotation>) -> Result<Answer, AocError> {
+        let mut angle = 50;
+        let mut sum = 0;
+        for rotation in rotations {
+            let (new_angle, added_clicks) = apply_rotation(angle, rotation);
+            angle = new_angle;
+            sum += added_clicks;
+        }
+
+        Ok(sum.into())
+    }
+}
+
+solution_fns! {
+    Day01(Vec<Rotation>);
+    /// How many rotations leave the dial at 0.
+    part1;
+    /// How many clicks of the dial land on 0, during rotations or at their end.
+    part2;
+}
+
+/// The dial so far, and how many times it stopped at and went through 0.
+pub struct Dial {
+    angle: i32,
+    stops_at_zero: i32,
+    clicks_at_zero: i32,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            angle: 50,
+            stops_at_zero: 0,
+            clicks_at_zero: 0,
+        }
+    }
+}
+
+impl Streaming for Day01 {
+    type State = Dial;
+
+    fn feed(dial: &mut Dial, record: &str) -> Result<(), AocError> {
+        let rotation = parse_input(parse_rotation, record)?;
+        let (new_angle, clicks) = apply_rotation(dial.angle, &rotation);
+
+        dial.angle = new_angle;
+        dial.clicks_at_zero += clicks;
+        if new_angle == 0 {
+            dial.stops_at_zero += 1;
+        }
+        Ok(())
+    }
+
+    fn finish(dial: &Dial, part: u8) -> Result<Answer, AocError> {
+        Ok(match part {
+            1 => dial.stops_at_zero,
+            _ => dial.clicks_at_zero,
+        }
+        .into())
+    }
+}
+
+impl Generate for Day01 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+                // Mostly less than a turn, with the odd one of several whole turns
+                let amount = if rng.chance(1, 4) {
+                    rng.range(100..=350)
+                } else {
+                    rng.range(1..=99)
+                };
+                format!("{direction}{amount}\n")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day01, Rotation};
+    use crate::check::Reference;
+    use crate::test_with_files;
+    use crate::{Answer, AocError};
+
+    test_with_files!(Day01, part1, part2; sample_full_turns, reference);
+
+    impl Reference for Day01 {
+        /// Turns the dial one click at a time.
+        fn reference(rotations: &Vec<Rotation>, part: u8) -> Result<Answer, AocError> {
+            let mut dial = 50;
+            let (mut stops_at_zero, mut passes_zero) = (0, 0);
+
+            for rotation in rotations {
+                let (step, amount) = match *rotation {
+                    Rotation::Left(amount) => (-1, amount),
+                    Rotation::Right(amount) => (1, amount),
+                };
+                for _ in 0..amount {
+                    dial = (dial + step + 100) % 100;
+                    if dial == 0 {
+                        passes_zero += 1;
+                    }
+                }
+                if dial == 0 {
+                    stops_at_zero += 1;
+                }
+            }
+
+            Ok(if part == 1 {
+                stops_at_zero
+            } else {
+                passes_zero
+            }
+            .into())
+        }
+    }
+}
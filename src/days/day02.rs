@@ -0,0 +1,185 @@
+use crate::generate::Generate;
+use crate::parse::{expected, line, parse_input};
+use crate::rng::Rng;
+use crate::stream::Streaming;
+use crate::{Answer, AocError, Solution, solution_fns};
+use std::ops::RangeInclusive;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::dec_int;
+use winnow::combinator::separated;
+
+pub struct Day02;
+
+fn parse_id_range(input: &mut &str) -> Result<RangeInclusive<i64>> {
+    (dec_int, "-", dec_int)
+        .map(|(start, _, end): (i64, &str, i64)| start..=end)
+        .context(expected("ID range such as `11-22`"))
+        .parse_next(input)
+}
+
+fn parse_id_ranges(input: &mut &str) -> Result<Vec<RangeInclusive<i64>>> {
+    line(separated(1.., parse_id_range, ",")).parse_next(input)
+}
+
+impl Solution for Day02 {
+    // The ranges as listed, not merged into a `RangeSet`: a range listed twice counts twice, and
+    // streaming only has to keep the two sums
+    type Parsed<'a> = Vec<RangeInclusive<i64>>;
+
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Vec<RangeInclusive<i64>>, AocError> {
+        parse_input(parse_id_ranges, input)
+    }
+
+    fn part1(id_ranges: &Vec<RangeInclusive<i64>>) -> Result<Answer, AocError> {
+        Ok(id_ranges
+            .iter()
+            .cloned()
+            .map(sum_doubled)
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(id_ranges: &Vec<RangeInclusive<i64>>) -> Result<Answer, AocError> {
+        Ok(id_ranges
+            .iter()
+            .cloned()
+            .map(sum_repeated)
+            .sum::<i64>()
+            .into())
+    }
+}
+
+solution_fns! {
+    Day02(Vec<RangeInclusive<i64>>);
+    /// The sum of the IDs made of some digits twice.
+    part1;
+    /// The sum of the IDs made of some digits at least twice.
+    part2;
+}
+
+/// The sum of the IDs in the range made of some digits twice, such as `123123`.
+pub fn sum_doubled(id_range: RangeInclusive<i64>) -> i64 {
+    let mut sum = 0i64;
+    for middle_number in id_range {
+        let middle_number_str = middle_number.to_string();
+        if middle_number_str.len() % 2 == 0 {
+            let (halve1, halve2) = middle_number_str.split_at(middle_number_str.len() / 2);
+            if halve1 == halve2 {
+                sum += middle_number;
+            }
+        }
+    }
+    sum
+}
+
+/// The sum of the IDs in the range made of some digits at least twice, such as `121212`.
+pub fn sum_repeated(id_range: RangeInclusive<i64>) -> i64 {
+    let mut sum = 0i64;
+    for middle_number in id_range {
+        let middle_number_str = middle_number.to_string();
+        for possible_pattern_length in 1..=middle_number_str.len() / 2 {
+            if middle_number_str.len() % possible_pattern_length != 0 {
+                continue;
+            }
+
+            let pattern_bytes = &middle_number_str.as_bytes()[0..possible_pattern_length];
+
+            if middle_number_str
+                .as_bytes()
+                .chunks(possible_pattern_length)
+                .all(|chunk| chunk == pattern_bytes)
+            {
+                sum += middle_number;
+                break;
+            }
+        }
+    }
+    sum
+}
+
+impl Streaming for Day02 {
+    /// The sums of both parts so far.
+    type State = (i64, i64);
+
+    const SEPARATOR: u8 = b',';
+
+    fn feed((doubled, repeated): &mut (i64, i64), record: &str) -> Result<(), AocError> {
+        let id_range = parse_input(parse_id_range, record)?;
+        *doubled += sum_doubled(id_range.clone());
+        *repeated += sum_repeated(id_range);
+        Ok(())
+    }
+
+    fn finish(&(doubled, repeated): &(i64, i64), part: u8) -> Result<Answer, AocError> {
+        Ok(if part == 1 { doubled } else { repeated }.into())
+    }
+}
+
+impl Generate for Day02 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let ranges: Vec<String> = (0..size)
+            .map(|_| {
+                let digits = rng.range(1..=6) as u32;
+                let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+                let end = start + rng.range(0..=200);
+                format!("{start}-{end}")
+            })
+            .collect();
+
+        ranges.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day02;
+    use crate::check::{self, Reference};
+    use crate::test_with_files;
+    use crate::{Answer, AocError};
+    use std::ops::RangeInclusive;
+
+    test_with_files!(Day02, part1, part2; reference);
+
+    /// Whether `id` is a block of `block` digits repeated at least twice, or exactly twice for
+    /// part 1, tested arithmetically: `123123` is `123 * 1001`.
+    fn is_repeated(id: i64, part: u8) -> bool {
+        let digits = id.to_string().len() as u32;
+
+        (1..digits)
+            .filter(|block| digits.is_multiple_of(*block) && (part == 2 || digits == 2 * block))
+            .any(|block| {
+                let repunit: i64 = (0..digits / block).map(|i| 10i64.pow(i * block)).sum();
+                id % repunit == 0 && id / repunit >= 10i64.pow(block - 1)
+            })
+    }
+
+    impl Reference for Day02 {
+        fn reference(id_ranges: &Vec<RangeInclusive<i64>>, part: u8) -> Result<Answer, AocError> {
+            Ok(id_ranges
+                .iter()
+                .cloned()
+                .flatten()
+                .filter(|&id| is_repeated(id, part))
+                .sum::<i64>()
+                .into())
+        }
+
+        /// The input without some of its ranges.
+        fn shrink(input: &str) -> Vec<String> {
+            let ranges: Vec<&str> = input.trim_end().split(',').collect();
+            check::without_chunks(&ranges)
+                .into_iter()
+                .map(|ranges| ranges.join(",") + "\n")
+                .collect()
+        }
+    }
+
+    #[test]
+    fn sums_each_range_even_when_they_overlap() {
+        let id_ranges = super::parse("11-22,11-22\n").unwrap();
+        assert_eq!(super::part1(&id_ranges).unwrap(), Answer::from(66i64));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::error::Location;
+use crate::generate::Generate;
+use crate::parse::{expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::stream::Streaming;
+use crate::{Answer, AocError, Solution, solution_fns};
+use winnow::Parser;
+use winnow::Result;
+use winnow::combinator::repeat;
+use winnow::token::one_of;
+
+pub struct Day03;
+
+fn parse_single_digit_int(input: &mut &str) -> Result<i32> {
+    one_of('0'..='9')
+        .map(|single_digit: char| {
+            single_digit
+                .to_digit(10)
+                .expect("single digit should be a digit")
+        })
+        .map(|digit| digit.try_into().expect("digit should fit into i32"))
+        .parse_next(input)
+}
+
+fn parse_bank(input: &mut &str) -> Result<Vec<i32>> {
+    repeat(1.., parse_single_digit_int)
+        .context(expected("bank of digits"))
+        .parse_next(input)
+}
+
+fn parse_banks(input: &mut &str) -> Result<Vec<Vec<i32>>> {
+    lines(parse_bank).parse_next(input)
+}
+
+/// The number of batteries part 2 turns on in each bank.
+const DOZEN: usize = 12;
+
+/// The largest joltage from turning on `usable_digits` batteries of the bank, keeping their order,
+/// or 0 if the bank has fewer batteries than that.
+pub fn recursive_max_joltage(remaining_bank: &[i32], usable_digits: usize) -> i64 {
+    if usable_digits == 0 {
+        return 0;
+    }
+    for starting_digit in (1..=9).rev() {
+        if let Some(pos) = remaining_bank.iter().position(|&d| d == starting_digit)
+            && pos + usable_digits <= remaining_bank.len()
+        {
+            let remaining_joltage =
+                recursive_max_joltage(&remaining_bank[pos + 1..], usable_digits - 1);
+            return i64::from(starting_digit)
+                * 10i64.pow(
+                    u32::try_from(usable_digits - 1).expect("usable digits should fit into u32"),
+                )
+                + remaining_joltage;
+        }
+    }
+    0
+}
+
+/// The largest joltage from turning on two batteries of the bank.
+pub fn max_pair_joltage(bank: &[i32]) -> i32 {
+    for first_digit in (1..=9).rev() {
+        if let Some(pos) = bank.iter().position(|&d| d == first_digit)
+            && pos < bank.len() - 1
+        {
+            let second = bank[pos + 1..].iter().max().unwrap();
+            return first_digit * 10 + second;
+        }
+    }
+    0
+}
+
+/// The error for the bank on `line`, which has fewer batteries than part 2 turns on.
+fn too_short(bank: &[i32], line: usize) -> AocError {
+    AocError::Invalid {
+        location: Some(Location {
+            line,
+            column: bank.len() + 1,
+            line_text: bank.iter().map(i32::to_string).collect(),
+        }),
+        reason: format!(
+            "bank has {} batteries, but part 2 turns on {DOZEN}",
+            bank.len()
+        ),
+    }
+}
+
+impl Solution for Day03 {
+    type Parsed<'a> = Vec<Vec<i32>>;
+
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
+        parse_input(parse_banks, input)
+    }
+
+    fn part1(banks: &Vec<Vec<i32>>) -> Result<Answer, AocError> {
+        let sum: i32 = banks.iter().map(|bank| max_pair_joltage(bank)).sum();
+        Ok(sum.into())
+    }
+
+    fn part2(banks: &Vec<Vec<i32>>) -> Result<Answer, AocError> {
+        let mut sum = 0;
+
+        for (i, bank) in banks.iter().enumerate() {
+            if bank.len() < DOZEN {
+                return Err(too_short(bank, i + 1));
+            }
+            sum += recursive_max_joltage(bank, DOZEN);
+        }
+
+        Ok(sum.into())
+    }
+}
+
+solution_fns! {
+    Day03(Vec<Vec<i32>>);
+    /// The sum of the largest joltage of each bank, with two batteries on.
+    part1;
+    /// The sum of the largest joltage of each bank, with twelve batteries on.
+    part2;
+}
+
+/// The sums of both parts over the banks so far.
+#[derive(Default)]
+pub struct Sums {
+    banks: usize,
+    pairs: i64,
+    dozens: i64,
+    /// The line and batteries of the first bank too short for part 2, which only fails that part.
+    short_bank: Option<(usize, Vec<i32>)>,
+}
+
+impl Streaming for Day03 {
+    type State = Sums;
+
+    fn feed(sums: &mut Sums, record: &str) -> Result<(), AocError> {
+        let bank = parse_input(parse_bank, record)?;
+        // Every line is fed, blank ones included, so banks are counted by line
+        sums.banks += 1;
+        sums.pairs += i64::from(max_pair_joltage(&bank));
+        if bank.len() < DOZEN {
+            sums.short_bank.get_or_insert((sums.banks, bank));
+        } else {
+            sums.dozens += recursive_max_joltage(&bank, DOZEN);
+        }
+        Ok(())
+    }
+
+    fn finish(sums: &Sums, part: u8) -> Result<Answer, AocError> {
+        match (part, &sums.short_bank) {
+            (1, _) => Ok(sums.pairs.into()),
+            (_, Some((line, bank))) => Err(too_short(bank, *line)),
+            (_, None) => Ok(sums.dozens.into()),
+        }
+    }
+}
+
+impl Generate for Day03 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                // At least the dozen batteries part 2 turns on
+                let batteries = rng.range(DOZEN as u64..=100);
+                let mut bank: String = (0..batteries)
+                    .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                    .collect();
+                bank.push('\n');
+                bank
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day03;
+    use crate::test_with_files;
+
+    test_with_files!(Day03, part1, part2);
+
+    #[test]
+    fn short_banks_only_fail_part_2() {
+        let banks = super::parse("987\n811\n").unwrap();
+        assert_eq!(super::part1(&banks).unwrap().to_string(), "179");
+
+        let error = super::part2(&banks).unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column), (1, 4));
+        assert_eq!(location.line_text, "987");
+    }
+}
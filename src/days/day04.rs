@@ -0,0 +1,111 @@
+use crate::generate::Generate;
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::parse::{expected, grid, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+use winnow::Parser;
+use winnow::Result;
+use winnow::combinator::alt;
+
+pub struct Day04;
+
+#[derive(Clone)]
+pub enum Square {
+    PaperRoll,
+    Empty,
+}
+
+fn parse_square(input: &mut &str) -> Result<Square> {
+    alt(('.'.map(|_| Square::Empty), '@'.map(|_| Square::PaperRoll)))
+        .context(expected("`.` or `@`"))
+        .parse_next(input)
+}
+
+fn parse_grid(input: &mut &str) -> Result<Grid<Square>> {
+    grid(parse_square).parse_next(input)
+}
+
+/// How many of the eight squares around `position` hold a paper roll.
+pub fn rolls_in_adjacent_squares(position: Point, grid: &Grid<Square>) -> usize {
+    grid.neighbours8(position)
+        .filter(|&adjacent| matches!(grid[adjacent], Square::PaperRoll))
+        .count()
+}
+
+/// The rolls with fewer than four rolls around them.
+fn accessible_rolls(grid: &Grid<Square>) -> impl Iterator<Item = Point> {
+    grid.find_all(|square| matches!(square, Square::PaperRoll))
+        .filter(|&roll| rolls_in_adjacent_squares(roll, grid) < 4)
+}
+
+impl Solution for Day04 {
+    type Parsed<'a> = Grid<Square>;
+
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Grid<Square>, AocError> {
+        parse_input(parse_grid, input)
+    }
+
+    fn part1(grid: &Grid<Square>) -> Result<Answer, AocError> {
+        Ok(accessible_rolls(grid).count().into())
+    }
+
+    fn part2(grid: &Grid<Square>) -> Result<Answer, AocError> {
+        let mut grid = grid.clone();
+        let mut removable_rolls = 0;
+
+        loop {
+            let erasable: Vec<Point> = accessible_rolls(&grid).collect();
+            if erasable.is_empty() {
+                break;
+            }
+
+            for roll in erasable {
+                grid[roll] = Square::Empty;
+                removable_rolls += 1;
+            }
+        }
+
+        Ok(removable_rolls.into())
+    }
+}
+
+solution_fns! {
+    Day04(Grid<Square>);
+    /// How many rolls have fewer than four rolls around them.
+    part1;
+    /// How many rolls can be removed, taking accessible rolls away until none are left.
+    part2;
+}
+
+impl Generate for Day04 {
+    /// A square grid, about two thirds paper rolls.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                let mut row: String = (0..side)
+                    .map(|_| if rng.chance(2, 3) { '@' } else { '.' })
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day04;
+    use crate::{test_rejects, test_with_files};
+
+    test_with_files!(Day04, part1, part2);
+
+    test_rejects!(Day04;
+        short_row: "..@\n@@\n" => (2, 3),
+        long_row: "@.\n@..\n" => (2, 3),
+        unknown_square: "@#\n" => (1, 2),
+    );
+}
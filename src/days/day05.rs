@@ -0,0 +1,223 @@
+use crate::error::Location;
+use crate::generate::Generate;
+use crate::parse::{blank_line, expected, lines, parse_input};
+use crate::ranges::RangeSet;
+use crate::rng::Rng;
+use crate::stream::Streaming;
+use crate::{Answer, AocError, Solution, solution_fns};
+use std::ops::RangeInclusive;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::dec_int;
+use winnow::combinator::separated_pair;
+
+pub struct Day05;
+
+pub struct Problem {
+    /// The fresh ID ranges as listed, which may overlap.
+    pub ranges: Vec<RangeInclusive<i64>>,
+    pub queries: Vec<i64>,
+}
+
+fn parse_range(input: &mut &str) -> Result<RangeInclusive<i64>> {
+    (dec_int, "-", dec_int)
+        .map(|(range_start, _, range_end)| range_start..=range_end)
+        .verify(|range: &RangeInclusive<i64>| !range.is_empty())
+        .context(expected("fresh ID range such as `3-5`"))
+        .parse_next(input)
+}
+
+fn parse_ranges(input: &mut &str) -> Result<Vec<RangeInclusive<i64>>> {
+    lines(parse_range).parse_next(input)
+}
+
+fn parse_query(input: &mut &str) -> Result<i64> {
+    dec_int.context(expected("ingredient ID")).parse_next(input)
+}
+
+fn parse_queries(input: &mut &str) -> Result<Vec<i64>> {
+    lines(parse_query).parse_next(input)
+}
+
+fn parse_problem(input: &mut &str) -> Result<Problem> {
+    separated_pair(parse_ranges, blank_line, parse_queries)
+        .map(|(ranges, queries)| Problem { ranges, queries })
+        .parse_next(input)
+}
+
+/// Every fresh ID, with the ranges merged.
+pub fn fresh_ids(problem: &Problem) -> RangeSet<i64> {
+    problem.ranges.iter().cloned().collect()
+}
+
+impl Solution for Day05 {
+    type Parsed<'a> = Problem;
+
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Problem, AocError> {
+        parse_input(parse_problem, input)
+    }
+
+    fn part1(problem: &Problem) -> Result<Answer, AocError> {
+        let fresh_ids = fresh_ids(problem);
+
+        Ok(problem
+            .queries
+            .iter()
+            .filter(|&&q| fresh_ids.contains(q))
+            .count()
+            .into())
+    }
+
+    fn part2(problem: &Problem) -> Result<Answer, AocError> {
+        Ok(fresh_ids(problem).len().into())
+    }
+}
+
+solution_fns! {
+    Day05(Problem);
+    /// How many of the queried ingredient IDs are fresh.
+    part1;
+    /// How many IDs the fresh ranges cover.
+    part2;
+}
+
+/// What streaming day 5 keeps: every range, which each query needs, but only a count of the
+/// queries.
+#[derive(Default)]
+pub struct Inventory {
+    fresh_ids: RangeSet<i64>,
+    /// Set once the blank line before the queries is read.
+    reading_queries: bool,
+    /// How many lines were fed, blank ones included.
+    lines: usize,
+    queries: usize,
+    fresh: usize,
+}
+
+impl Streaming for Day05 {
+    type State = Inventory;
+
+    fn feed(inventory: &mut Inventory, record: &str) -> Result<(), AocError> {
+        inventory.lines += 1;
+        if inventory.reading_queries {
+            let query = parse_input(parse_query, record)?;
+            inventory.queries += 1;
+            if inventory.fresh_ids.contains(query) {
+                inventory.fresh += 1;
+            }
+        } else if record.is_empty() && !inventory.fresh_ids.is_empty() {
+            inventory.reading_queries = true;
+        } else {
+            let range = parse_input(parse_range, record)?;
+            inventory.fresh_ids.insert(range);
+        }
+        Ok(())
+    }
+
+    fn finish(inventory: &Inventory, part: u8) -> Result<Answer, AocError> {
+        // Where the whole input would have failed too: at its end, expecting the blank line
+        if inventory.queries == 0 {
+            return Err(AocError::Parse {
+                location: Location {
+                    line: inventory.lines + 1,
+                    column: 1,
+                    line_text: String::new(),
+                },
+                expected: Some("blank line".to_string()),
+            });
+        }
+
+        Ok(match part {
+            1 => inventory.fresh.into(),
+            _ => inventory.fresh_ids.len().into(),
+        })
+    }
+}
+
+impl Generate for Day05 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // Few enough IDs that ranges overlap, touch and nest often
+        let ranges: String = (0..size)
+            .map(|_| {
+                let start = rng.range(0..=60);
+                format!("{start}-{}\n", start + rng.range(0..=15))
+            })
+            .collect();
+        let queries: String = (0..size)
+            .map(|_| format!("{}\n", rng.range(0..=80)))
+            .collect();
+
+        format!("{ranges}\n{queries}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day05, Problem};
+    use crate::check::{self, Reference};
+    use crate::parse::normalize;
+    use crate::stream;
+    use crate::{Answer, AocError, Solution};
+    use crate::{test_rejects, test_with_files};
+    use std::collections::BTreeSet;
+
+    test_with_files!(Day05, part1, part2; reference);
+
+    test_rejects!(Day05;
+        backwards_range: "3-5\n8-6\n\n4\n" => (2, 1),
+        missing_queries: "3-5\n\n" => (2, 1),
+    );
+
+    #[test]
+    fn streaming_points_missing_queries_at_the_end() {
+        for input in ["3-5\n\n", "3-5\n4-6\n"] {
+            let solutions = stream::solve::<Day05>(&mut input.as_bytes(), &[1]).unwrap();
+            let streamed = solutions[0].as_ref().unwrap_err().location().cloned();
+            let whole = Day05::parse(&normalize(input))
+                .err()
+                .and_then(|e| e.location().cloned());
+            assert_eq!(streamed, whole, "{input:?}");
+        }
+    }
+
+    impl Reference for Day05 {
+        /// Checks every query against every range, and counts fresh IDs one by one.
+        fn reference(problem: &Problem, part: u8) -> Result<Answer, AocError> {
+            if part == 1 {
+                return Ok(problem
+                    .queries
+                    .iter()
+                    .filter(|&&q| problem.ranges.iter().any(|range| range.contains(&q)))
+                    .count()
+                    .into());
+            }
+
+            let fresh: BTreeSet<i64> = problem
+                .ranges
+                .iter()
+                .flat_map(|range| range.clone())
+                .collect();
+            Ok(fresh.len().into())
+        }
+
+        /// Ranges and queries without some of their lines, keeping the blank line between them.
+        fn shrink(input: &str) -> Vec<String> {
+            let (ranges, queries) = input.split_once("\n\n").unwrap_or((input, ""));
+            let ranges: Vec<&str> = ranges.lines().collect();
+            let queries: Vec<&str> = queries.lines().collect();
+            let join = |ranges: &[&str], queries: &[&str]| {
+                format!("{}\n\n{}\n", ranges.join("\n"), queries.join("\n"))
+            };
+
+            let fewer_ranges = check::without_chunks(&ranges)
+                .into_iter()
+                .map(|ranges| join(&ranges, &queries));
+            let fewer_queries = check::without_chunks(&queries)
+                .into_iter()
+                .map(|queries| join(&ranges, &queries));
+            fewer_ranges.chain(fewer_queries).collect()
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::generate::Generate;
+use crate::parse::{expected, line_end, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::{line_ending, space0, space1};
+use winnow::combinator::{alt, delimited, repeat, separated};
+use winnow::token::one_of;
+
+pub struct Day06;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Sum,
+    Product,
+}
+
+impl Operation {
+    pub fn apply(&self, numbers: &[i64]) -> i64 {
+        match self {
+            Operation::Sum => numbers.iter().sum(),
+            Operation::Product => numbers.iter().product(),
+        }
+    }
+}
+
+/// The worksheet is kept as characters because each part reads the numbers differently.
+pub struct Worksheet {
+    pub chars_matrix: Vec<Vec<char>>,
+    pub operations_row: Vec<Operation>,
+}
+
+fn parse_numbers_line(input: &mut &str) -> Result<Vec<char>> {
+    repeat(1.., one_of(('0'..='9', ' ')))
+        .context(expected("row of digits and spaces"))
+        .parse_next(input)
+}
+
+fn parse_operation(input: &mut &str) -> Result<Operation> {
+    alt(('+'.value(Operation::Sum), '*'.value(Operation::Product)))
+        .context(expected("`+` or `*`"))
+        .parse_next(input)
+}
+
+fn parse_operations_row(input: &mut &str) -> Result<Vec<Operation>> {
+    delimited(space0, separated(1.., parse_operation, space1), space0).parse_next(input)
+}
+
+fn parse_math_problem(input: &mut &str) -> Result<Worksheet> {
+    (
+        separated(1.., parse_numbers_line, line_ending),
+        line_ending,
+        parse_operations_row,
+        line_end,
+    )
+        .map(|(chars_matrix, _, operations_row, _)| Worksheet {
+            chars_matrix,
+            operations_row,
+        })
+        .parse_next(input)
+}
+
+/// Checks that every row is as wide as the first, since the problems are told apart by their
+/// columns. A row that is too short fails at its end, and one that is too long at its first
+/// extra character.
+fn check_widths(input: &str) -> Result<(), AocError> {
+    let width = input.lines().next().map_or(0, str::len);
+    let mut offset = 0;
+
+    for row in input.split_inclusive('\n') {
+        let row_width = row.trim_end_matches(['\r', '\n']).len();
+        if row_width != width {
+            return Err(AocError::invalid_at(
+                input,
+                offset + row_width.min(width),
+                format!("row is {row_width} characters wide, but the first row is {width}"),
+            ));
+        }
+        offset += row.len();
+    }
+    Ok(())
+}
+
+fn char_vec_to_i64(char_vec: &[char]) -> Option<i64> {
+    if char_vec.iter().all(|&c| c == ' ') {
+        None
+    } else {
+        Some(
+            char_vec
+                .iter()
+                .filter(|c| c.is_numeric())
+                .collect::<String>()
+                .parse()
+                .expect("could not transform char vec to i64"),
+        )
+    }
+}
+
+impl Solution for Day06 {
+    type Parsed<'a> = Worksheet;
+
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Worksheet, AocError> {
+        let worksheet = parse_input(parse_math_problem, input)?;
+        check_widths(input)?;
+        Ok(worksheet)
+    }
+
+    fn part1(worksheet: &Worksheet) -> Result<Answer, AocError> {
+        let number_rows: Vec<Vec<i64>> = worksheet
+            .chars_matrix
+            .iter()
+            .map(|row| {
+                row.split(|&c| c == ' ')
+                    .filter_map(char_vec_to_i64)
+                    .collect()
+            })
+            .collect();
+
+        let transposed_numbers: Vec<Vec<i64>> = (0..number_rows[0].len())
+            .map(|i| number_rows.iter().map(|inner| inner[i]).collect())
+            .collect();
+
+        Ok(worksheet
+            .operations_row
+            .iter()
+            .zip(&transposed_numbers)
+            .map(|(op, col)| op.apply(col))
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(worksheet: &Worksheet) -> Result<Answer, AocError> {
+        let chars_matrix = &worksheet.chars_matrix;
+
+        let transposed_char_matrix: Vec<Vec<char>> = (0..chars_matrix[0].len())
+            .map(|i| chars_matrix.iter().map(|inner| inner[i]).collect())
+            .collect();
+
+        let numbers_separated_by_none: Vec<Option<i64>> = transposed_char_matrix
+            .iter()
+            .map(|vec| char_vec_to_i64(vec))
+            .collect();
+
+        let number_columns: Vec<Vec<i64>> = numbers_separated_by_none
+            .split(Option::is_none)
+            .map(|col| col.iter().filter_map(|&x| x).collect())
+            .collect();
+
+        Ok(worksheet
+            .operations_row
+            .iter()
+            .zip(&number_columns)
+            .map(|(op, col)| op.apply(col))
+            .sum::<i64>()
+            .into())
+    }
+}
+
+solution_fns! {
+    Day06(Worksheet);
+    /// The grand total of the problems, reading their numbers along the rows.
+    part1;
+    /// The grand total of the problems, reading their numbers down the columns.
+    part2;
+}
+
+impl Generate for Day06 {
+    /// A worksheet of `size` problems side by side, each as wide as its longest number, with the
+    /// shorter ones pushed to the left or the right of it.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let rows = if rng.chance(1, 2) { 3 } else { 4 };
+        let mut lines = vec![String::new(); rows + 1];
+
+        for problem in 0..size.max(1) {
+            let width = rng.range(1..=4) as usize;
+            let longest = rng.below(rows);
+            let left_aligned = rng.chance(1, 2);
+
+            for (row, line) in lines.iter_mut().take(rows).enumerate() {
+                let digits = if row == longest {
+                    width
+                } else {
+                    rng.range(1..=width as u64) as usize
+                };
+                let number: String = (0..digits)
+                    .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                    .collect();
+
+                if problem > 0 {
+                    line.push(' ');
+                }
+                if left_aligned {
+                    *line += &format!("{number:<width$}");
+                } else {
+                    *line += &format!("{number:>width$}");
+                }
+            }
+
+            let operation = if rng.chance(1, 2) { '+' } else { '*' };
+            if problem > 0 {
+                lines[rows].push(' ');
+            }
+            lines[rows] += &format!("{operation:<width$}");
+        }
+
+        lines.into_iter().map(|line| line + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day06;
+    use crate::{test_rejects, test_with_files};
+
+    test_with_files!(Day06, part1, part2);
+
+    test_rejects!(Day06;
+        short_row: "123 328\n45\n+   *  \n" => (2, 3),
+        long_row: "1 2\n3 45\n+ *\n" => (2, 4),
+        short_operations_row: "1 23\n4 5 \n+ *\n" => (3, 4),
+    );
+}
@@ -0,0 +1,290 @@
+use crate::generate::Generate;
+use crate::geom::{Direction, Point};
+use crate::grid::{Grid, Tile};
+use crate::parse::{expected, grid, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, debug, solution_fns};
+use std::collections::{HashMap, HashSet, VecDeque};
+use winnow::Parser;
+use winnow::Result;
+use winnow::combinator::alt;
+
+pub struct Day07;
+
+pub enum Square {
+    Empty,
+    Start,
+    Splitter,
+}
+
+impl Tile for Square {
+    fn to_char(&self) -> char {
+        match self {
+            Square::Empty => '.',
+            Square::Start => 'S',
+            Square::Splitter => '^',
+        }
+    }
+}
+
+/// Where a beam is, or `None` once it has left the manifold past its first column.
+type Beam = Option<Point>;
+
+type Splits = Option<(Beam, Beam)>;
+
+/// The manifold's squares, and the start found in them while parsing.
+pub struct Manifold {
+    pub grid: Grid<Square>,
+    /// Where the beam enters the manifold.
+    pub start: Point,
+}
+
+/// The beams a splitter sends to its left and to its right.
+fn split(splitter: Point) -> (Beam, Beam) {
+    (
+        splitter.step(Direction::Left),
+        splitter.step(Direction::Right),
+    )
+}
+
+fn parse_square(input: &mut &str) -> Result<Square> {
+    alt((
+        '.'.map(|_| Square::Empty),
+        '^'.map(|_| Square::Splitter),
+        'S'.map(|_| Square::Start),
+    ))
+    .context(expected("`.`, `^` or `S`"))
+    .parse_next(input)
+}
+
+fn parse_grid(input: &mut &str) -> Result<Grid<Square>> {
+    grid(parse_square).parse_next(input)
+}
+
+fn expand_laser_once(
+    laser_start: Beam,
+    grid: &Grid<Square>,
+    visited: &mut HashSet<Point>,
+) -> Splits {
+    let mut current_point = laser_start;
+
+    while let Some(point) = current_point
+        && let Some(s) = grid.get(point)
+    {
+        if visited.contains(&point) {
+            break;
+        }
+
+        visited.insert(point);
+        match s {
+            Square::Empty | Square::Start => current_point = point.step(Direction::Down),
+            Square::Splitter => return Some(split(point)),
+        }
+    }
+
+    None
+}
+
+fn expand_laser(
+    laser_start: Beam,
+    grid: &Grid<Square>,
+    saved_paths: &mut HashMap<Point, i128>,
+) -> i128 {
+    let mut current_point = laser_start;
+    while let Some(point) = current_point
+        && let Some(s) = grid.get(point)
+    {
+        match s {
+            Square::Empty | Square::Start => current_point = point.step(Direction::Down),
+            Square::Splitter => {
+                return number_of_paths_from_splitter(point, grid, saved_paths);
+            }
+        }
+    }
+
+    1
+}
+
+fn number_of_paths_from_splitter(
+    splitter: Point,
+    grid: &Grid<Square>,
+    saved_paths: &mut HashMap<Point, i128>,
+) -> i128 {
+    if let Some(&n_paths) = saved_paths.get(&splitter) {
+        n_paths
+    } else {
+        let (left, right) = split(splitter);
+        let mut n_paths = 0i128;
+
+        n_paths += expand_laser(left, grid, saved_paths);
+        n_paths += expand_laser(right, grid, saved_paths);
+
+        saved_paths.insert(splitter, n_paths);
+        n_paths
+    }
+}
+
+/// Draws the grid with every square a beam went through, as `|`, `V` and `X`.
+fn render_beams(grid: &Grid<Square>, visited: &HashSet<Point>) -> Grid<char> {
+    grid.map(|point, square| match (visited.contains(&point), square) {
+        (true, Square::Empty) => '|',
+        (true, Square::Start) => 'V',
+        (true, Square::Splitter) => 'X',
+        (false, square) => square.to_char(),
+    })
+}
+
+impl Solution for Day07 {
+    type Parsed<'a> = Manifold;
+
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Result<Manifold, AocError> {
+        let grid = parse_input(parse_grid, input)?;
+        // No single square is wrong, so the error points past the end, where `S` still wasn't
+        let start = grid
+            .find(|square| matches!(square, Square::Start))
+            .ok_or_else(|| {
+                AocError::invalid_at(input, input.len(), "no starting square `S` found")
+            })?;
+
+        Ok(Manifold { grid, start })
+    }
+
+    fn part1(manifold: &Manifold) -> Result<Answer, AocError> {
+        let (grid, start) = (&manifold.grid, manifold.start);
+        let mut queue: VecDeque<Beam> = VecDeque::from([Some(start)]);
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut splitters = 0;
+
+        while let Some(laser_start) = queue.pop_front() {
+            if let Some((left, right)) = expand_laser_once(laser_start, grid, &mut visited) {
+                splitters += 1;
+                queue.push_back(left);
+                queue.push_back(right);
+            }
+        }
+
+        debug!("beams:\n{}", render_beams(grid, &visited));
+
+        Ok(splitters.into())
+    }
+
+    fn part2(manifold: &Manifold) -> Result<Answer, AocError> {
+        let (grid, start) = (&manifold.grid, manifold.start);
+        let mut saved_paths: HashMap<Point, i128> = HashMap::new();
+
+        Ok(expand_laser(Some(start), grid, &mut saved_paths).into())
+    }
+}
+
+solution_fns! {
+    Day07(Manifold);
+    /// How many splitters the beam hits.
+    part1;
+    /// How many timelines a single particle ends up in.
+    part2;
+}
+
+impl Generate for Day07 {
+    /// A manifold with `S` at the middle of the top row and splitters on every other row, never
+    /// on the edges nor next to each other, like the puzzle's.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = 2 * size + 3;
+        let empty = ".".repeat(width) + "\n";
+        let mut manifold = format!("{}S{}\n", ".".repeat(width / 2), ".".repeat(width / 2));
+
+        for _ in 0..size {
+            manifold += &empty;
+            let mut row = vec!['.'; width];
+            let mut j = 1;
+            while j < width - 1 {
+                if rng.chance(1, 3) {
+                    row[j] = '^';
+                    j += 1;
+                }
+                j += 1;
+            }
+            manifold.extend(row);
+            manifold.push('\n');
+        }
+
+        manifold + &empty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day07, Manifold, Point, Square};
+    use crate::check::Reference;
+    use crate::{Answer, AocError};
+    use crate::{test_rejects, test_with_files};
+    use std::collections::HashSet;
+
+    test_with_files!(Day07, part1, part2; reference);
+
+    test_rejects!(Day07;
+        ragged_rows: "S..\n.^\n" => (2, 3),
+        unknown_square: "..S..\n..x..\n" => (2, 3),
+        // At the end of the input, since `S` could have been on any square
+        missing_start: ".....\n..^..\n" => (3, 1),
+    );
+
+    impl Reference for Day07 {
+        /// Moves every beam down one row at a time, keeping how many timelines reach each square.
+        fn reference(manifold: &Manifold, part: u8) -> Result<Answer, AocError> {
+            let (grid, start) = (&manifold.grid, manifold.start);
+            let mut timelines = vec![0i128; grid.width()];
+            timelines[start.j] = 1;
+            let mut splitters_hit = HashSet::new();
+
+            for (i, row) in grid.rows().enumerate().skip(start.i + 1) {
+                let mut below = vec![0; timelines.len()];
+                for (j, &count) in timelines
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, count)| **count > 0)
+                {
+                    match row[j] {
+                        Square::Splitter => {
+                            splitters_hit.insert((i, j));
+                            below[j - 1] += count;
+                            below[j + 1] += count;
+                        }
+                        Square::Empty | Square::Start => below[j] += count,
+                    }
+                }
+                timelines = below;
+            }
+
+            Ok(match part {
+                1 => splitters_hit.len().into(),
+                _ => timelines.iter().sum::<i128>().into(),
+            })
+        }
+
+        /// One `S`, on the top row, and no splitters on the edges or next to each other, so no
+        /// beam leaves the manifold sideways or lands on a splitter.
+        fn is_valid(manifold: &Manifold) -> bool {
+            let grid = &manifold.grid;
+            let width = grid.width();
+            let starts: Vec<_> = grid.find_all(|s| matches!(s, Square::Start)).collect();
+
+            matches!(starts[..], [Point { i: 0, .. }])
+                && grid.rows().all(|row| {
+                    !matches!(row[0], Square::Splitter)
+                        && !matches!(row[width - 1], Square::Splitter)
+                        && row
+                            .windows(2)
+                            .all(|pair| !matches!(pair, [Square::Splitter, Square::Splitter]))
+                })
+        }
+    }
+
+    #[test]
+    fn beams_leave_past_the_first_column() {
+        let manifold = super::parse("S..\n^..\n...\n").unwrap();
+        assert_eq!(super::part1(&manifold).unwrap().to_string(), "1");
+        assert_eq!(super::part2(&manifold).unwrap().to_string(), "2");
+    }
+}
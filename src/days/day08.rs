@@ -0,0 +1,148 @@
+use crate::generate::Generate;
+use crate::parse::{expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+use std::collections::HashMap;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::dec_int;
+
+pub struct Day08;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct JunctionBox {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl JunctionBox {
+    pub fn square_distance_to(&self, other: &JunctionBox) -> i64 {
+        (self.x - other.x).pow(2) + (self.y - other.y).pow(2) + (self.z - other.z).pow(2)
+    }
+}
+
+fn parse_junction_box(input: &mut &str) -> Result<JunctionBox> {
+    (dec_int, ",", dec_int, ",", dec_int)
+        .map(|(x, _, y, _, z)| JunctionBox { x, y, z })
+        .context(expected("junction box such as `162,817,812`"))
+        .parse_next(input)
+}
+
+fn parse_junction_boxes(input: &mut &str) -> Result<Vec<JunctionBox>> {
+    lines(parse_junction_box).parse_next(input)
+}
+
+/// Every pair of boxes as `(square distance, i, j)`, closest pairs first.
+pub fn sorted_distances(boxes: &[JunctionBox]) -> Vec<(i64, usize, usize)> {
+    let distances_matrix = {
+        let mut distances_matrix = vec![vec![i64::MAX; boxes.len()]; boxes.len()];
+
+        for (i, i_box) in boxes.iter().enumerate() {
+            for (j, j_box) in boxes.iter().enumerate().skip(i + 1) {
+                distances_matrix[i][j] = i_box.square_distance_to(j_box);
+            }
+        }
+        distances_matrix
+    };
+
+    let mut distances_vec: Vec<(i64, usize, usize)> = distances_matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &dist)| (dist, i, j)))
+        .collect();
+
+    distances_vec.sort_unstable();
+    distances_vec
+}
+
+impl Solution for Day08 {
+    type Parsed<'a> = Vec<JunctionBox>;
+
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Vec<JunctionBox>, AocError> {
+        let mut boxes = parse_input(parse_junction_boxes, input)?;
+        boxes.sort_unstable();
+        Ok(boxes)
+    }
+
+    fn part1(boxes: &Vec<JunctionBox>) -> Result<Answer, AocError> {
+        let sorted_distances_vec = sorted_distances(boxes);
+
+        let mut component: Vec<usize> = (0..boxes.len()).collect();
+
+        for &(_, i, j) in sorted_distances_vec.iter().take(1000) {
+            let component_i = component[i];
+            let component_j = component[j];
+
+            for c in &mut component {
+                if *c == component_j {
+                    *c = component_i;
+                }
+            }
+        }
+
+        let counts = component.iter().fold(HashMap::new(), |mut map, c| {
+            *map.entry(c).or_default() += 1;
+            map
+        });
+
+        let mut values: Vec<i32> = counts.into_values().collect();
+        values.sort_unstable();
+        Ok(values.iter().rev().take(3).product::<i32>().into())
+    }
+
+    fn part2(boxes: &Vec<JunctionBox>) -> Result<Answer, AocError> {
+        let sorted_distances_vec = sorted_distances(boxes);
+
+        let mut component: Vec<usize> = (0..boxes.len()).collect();
+
+        for &(_, i, j) in &sorted_distances_vec {
+            let component_i = component[i];
+            let component_j = component[j];
+
+            for c in &mut component {
+                if *c == component_j {
+                    *c = component_i;
+                }
+            }
+
+            if component.iter().all(|c| *c == component_i) {
+                // this connection completed the circuit
+                return Ok((boxes[i].x * boxes[j].x).into());
+            }
+        }
+
+        unreachable!("all boxes should join the same component");
+    }
+}
+
+solution_fns! {
+    Day08(Vec<JunctionBox>);
+    /// The product of the sizes of the three largest circuits, after connecting the 1000 closest
+    /// pairs of boxes.
+    part1;
+    /// The product of the X coordinates of the two boxes whose connection makes a single circuit.
+    part2;
+}
+
+impl Generate for Day08 {
+    /// At least two boxes, so they can be connected.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(2))
+            .map(|_| {
+                let [x, y, z] = [(); 3].map(|()| rng.range(0..=99_999));
+                format!("{x},{y},{z}\n")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day08;
+    use crate::test_with_files;
+
+    test_with_files!(Day08, part1, part2);
+}
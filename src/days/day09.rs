@@ -0,0 +1,336 @@
+use crate::generate::Generate;
+use crate::geom::Point;
+use crate::grid::Grid;
+use crate::parse::{expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns, trace};
+use std::cmp::{max, min};
+use std::collections::HashMap;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::dec_uint;
+use winnow::combinator::separated_pair;
+
+pub struct Day09;
+
+/// The area of the rectangle with `p` and `q` at opposite corners, in tiles.
+pub fn area(p: Point, q: Point) -> usize {
+    (p.i.abs_diff(q.i) + 1) * (p.j.abs_diff(q.j) + 1)
+}
+
+/// The tiles of the rectangle with `p` and `q` at opposite corners.
+fn points_in_rect(p: Point, q: Point) -> impl Iterator<Item = Point> {
+    let (upper_left, lower_right) = (
+        Point::new(min(p.i, q.i), min(p.j, q.j)),
+        Point::new(max(p.i, q.i), max(p.j, q.j)),
+    );
+
+    (upper_left.i..=lower_right.i)
+        .flat_map(move |i| (upper_left.j..=lower_right.j).map(move |j| Point { i, j }))
+}
+
+/// Maps the values of one coordinate to consecutive small ones, keeping their order.
+#[derive(Debug)]
+pub struct CoordinateCompressor {
+    compress_map: HashMap<usize, usize>,
+    decompress_map: HashMap<usize, usize>,
+}
+
+impl CoordinateCompressor {
+    pub fn new(coordinate_values: &[usize]) -> Self {
+        // The value after each one too, so the gap between two values that aren't adjacent keeps
+        // a row or column of its own, and outside areas squeezed between edges don't disappear
+        let mut sorted_deduped_values: Vec<usize> = coordinate_values
+            .iter()
+            .flat_map(|&value| [value, value + 1])
+            .collect();
+        sorted_deduped_values.sort_unstable();
+        sorted_deduped_values.dedup();
+
+        let compress: HashMap<usize, usize> = sorted_deduped_values.into_iter().zip(0..).collect();
+        let decompress: HashMap<usize, usize> = compress
+            .iter()
+            .map(|(&i, &compressed_i)| (compressed_i, i))
+            .collect();
+
+        CoordinateCompressor {
+            compress_map: compress,
+            decompress_map: decompress,
+        }
+    }
+
+    pub fn compress(&self, val: usize) -> usize {
+        self.compress_map[&val]
+    }
+
+    pub fn decompress(&self, val: usize) -> usize {
+        self.decompress_map[&val]
+    }
+
+    fn len(&self) -> usize {
+        self.compress_map.len()
+    }
+}
+
+/// A [`CoordinateCompressor`] for each coordinate of some points.
+#[derive(Debug)]
+pub struct PointCompressor {
+    compressor_i: CoordinateCompressor,
+    compressor_j: CoordinateCompressor,
+}
+
+impl PointCompressor {
+    pub fn new(points: &[Point]) -> Self {
+        let i_values: Vec<usize> = points.iter().map(|p| p.i).collect();
+        let compressor_i = CoordinateCompressor::new(&i_values);
+
+        let j_values: Vec<usize> = points.iter().map(|p| p.j).collect();
+        let compressor_j = CoordinateCompressor::new(&j_values);
+
+        PointCompressor {
+            compressor_i,
+            compressor_j,
+        }
+    }
+
+    pub fn compress(&self, point: &Point) -> Point {
+        Point {
+            i: self.compressor_i.compress(point.i),
+            j: self.compressor_j.compress(point.j),
+        }
+    }
+
+    pub fn decompress(&self, point: &Point) -> Point {
+        Point {
+            i: self.compressor_i.decompress(point.i),
+            j: self.compressor_j.decompress(point.j),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.compressor_i.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.compressor_j.len()
+    }
+}
+
+fn parse_point(input: &mut &str) -> Result<Point> {
+    separated_pair(dec_uint, ",", dec_uint)
+        .map(|(i, j)| Point { i, j })
+        .context(expected("red tile such as `7,1`"))
+        .parse_next(input)
+}
+
+fn parse_points(input: &mut &str) -> Result<Vec<Point>> {
+    lines(parse_point).parse_next(input)
+}
+
+fn flood_fill_interior(is_edge: &Grid<bool>) -> Grid<bool> {
+    let mut is_interior = Grid::new(is_edge.width(), is_edge.height(), true);
+    let (rows, columns) = (is_interior.height(), is_interior.width());
+    let mut flood_stack: Vec<Point> = Vec::new();
+
+    for i in 0..rows {
+        flood_stack.push(Point::new(i, 0));
+        flood_stack.push(Point::new(i, columns - 1));
+    }
+
+    for j in 0..columns {
+        flood_stack.push(Point::new(0, j));
+        flood_stack.push(Point::new(rows - 1, j));
+    }
+
+    while let Some(current_point) = flood_stack.pop() {
+        if !is_interior[current_point] || is_edge[current_point] {
+            continue;
+        }
+        is_interior[current_point] = false;
+
+        flood_stack.extend(is_interior.neighbours4(current_point));
+    }
+
+    is_interior
+}
+
+impl Solution for Day09 {
+    type Parsed<'a> = Vec<Point>;
+
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Vec<Point>, AocError> {
+        parse_input(parse_points, input)
+    }
+
+    fn part1(points: &Vec<Point>) -> Result<Answer, AocError> {
+        let mut max_area = 0;
+        for (pi, p1) in points.iter().enumerate() {
+            for p2 in points.iter().skip(pi + 1) {
+                max_area = max(max_area, area(*p1, *p2));
+            }
+        }
+        Ok(max_area.into())
+    }
+
+    fn part2(points: &Vec<Point>) -> Result<Answer, AocError> {
+        let compressor = PointCompressor::new(points);
+        let compressed_points: Vec<Point> = points.iter().map(|p| compressor.compress(p)).collect();
+
+        let is_edge = {
+            let mut is_edge = Grid::new(compressor.columns(), compressor.rows(), false);
+
+            for pi in 0..compressed_points.len() {
+                let edge_start = &compressed_points[pi];
+                let edge_end = &compressed_points[(&pi + 1) % points.len()];
+
+                for edge_point in points_in_rect(*edge_start, *edge_end) {
+                    is_edge[edge_point] = true;
+                }
+            }
+            is_edge
+        };
+
+        let is_interior = flood_fill_interior(&is_edge);
+
+        trace!("interior of the compressed loop:\n{is_interior}");
+
+        let mut max_area: usize = 0;
+
+        for (pi, p1) in compressed_points.iter().enumerate() {
+            for p2 in compressed_points.iter().skip(pi + 1) {
+                let dcp1 = compressor.decompress(p1);
+                let dcp2 = compressor.decompress(p2);
+
+                let new_area = area(dcp1, dcp2);
+
+                if new_area > max_area {
+                    // check if viable
+                    if points_in_rect(*p1, *p2).all(|p| is_interior[p]) {
+                        max_area = new_area;
+                    }
+                }
+            }
+        }
+
+        Ok(max_area.into())
+    }
+}
+
+solution_fns! {
+    Day09(Vec<Point>);
+    /// The largest rectangle with red tiles at two opposite corners.
+    part1;
+    /// The largest rectangle with red tiles at two opposite corners and only red or green tiles
+    /// inside.
+    part2;
+}
+
+impl Generate for Day09 {
+    /// The red tiles of a histogram: a staircase of columns of random widths and heights on a
+    /// flat base, walked around clockwise, and maybe flipped or turned on its side.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let base = rng.range(0..=3) as usize;
+        let first = rng.range(0..=3) as usize;
+        let mut columns: Vec<(usize, usize, usize)> = Vec::new();
+        let mut x = first;
+
+        for _ in 0..size {
+            let height = base + rng.range(1..=6) as usize;
+            let end = x + rng.range(1..=4) as usize;
+            match columns.last_mut() {
+                Some((_, last_end, last_height)) if *last_height == height => *last_end = end,
+                _ => columns.push((x, end, height)),
+            }
+            x = end;
+        }
+
+        let mut corners: Vec<(usize, usize)> = columns
+            .iter()
+            .flat_map(|&(start, end, height)| [(start, height), (end, height)])
+            .collect();
+        corners.extend([(x, base), (first, base)]);
+
+        let flip = rng.chance(1, 2);
+        let transpose = rng.chance(1, 2);
+        corners
+            .into_iter()
+            .map(|(x, y)| {
+                let y = if flip { 10 - y } else { y };
+                let (i, j) = if transpose { (y, x) } else { (x, y) };
+                format!("{i},{j}\n")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day09, Point, area};
+    use crate::check::Reference;
+    use crate::test_with_files;
+    use crate::{Answer, AocError};
+
+    test_with_files!(Day09, part1, part2; sample_squeezed_gap, reference);
+
+    impl Reference for Day09 {
+        /// Draws the loop tile by tile, floods the outside from a border around it, and tries
+        /// every rectangle against the tiles left.
+        fn reference(points: &Vec<Point>, part: u8) -> Result<Answer, AocError> {
+            let rect = |p: &Point, q: &Point| {
+                let (i0, i1) = (p.i.min(q.i), p.i.max(q.i));
+                let (j0, j1) = (p.j.min(q.j), p.j.max(q.j));
+                (i0..=i1).flat_map(move |i| (j0..=j1).map(move |j| (i, j)))
+            };
+
+            // Shifted by one so the border around the loop stays in bounds
+            let rows = points.iter().map(|p| p.i).max().unwrap_or(0) + 3;
+            let columns = points.iter().map(|p| p.j).max().unwrap_or(0) + 3;
+            let mut on_loop = vec![vec![false; columns]; rows];
+            for (p, q) in points.iter().zip(points.iter().cycle().skip(1)) {
+                for (i, j) in rect(p, q) {
+                    on_loop[i + 1][j + 1] = true;
+                }
+            }
+
+            let mut outside = vec![vec![false; columns]; rows];
+            let mut stack = vec![(0, 0)];
+            while let Some((i, j)) = stack.pop() {
+                if on_loop[i][j] || outside[i][j] {
+                    continue;
+                }
+                outside[i][j] = true;
+                if i > 0 {
+                    stack.push((i - 1, j));
+                }
+                if i + 1 < rows {
+                    stack.push((i + 1, j));
+                }
+                if j > 0 {
+                    stack.push((i, j - 1));
+                }
+                if j + 1 < columns {
+                    stack.push((i, j + 1));
+                }
+            }
+
+            let mut max_area = 0;
+            for (pi, p) in points.iter().enumerate() {
+                for q in &points[pi + 1..] {
+                    if part == 1 || rect(p, q).all(|(i, j)| !outside[i + 1][j + 1]) {
+                        max_area = max_area.max(area(*p, *q));
+                    }
+                }
+            }
+            Ok(max_area.into())
+        }
+
+        /// Consecutive red tiles, wrapping around, share a row or a column.
+        fn is_valid(points: &Vec<Point>) -> bool {
+            points
+                .iter()
+                .zip(points.iter().cycle().skip(1))
+                .all(|(p, q)| (p.i == q.i) != (p.j == q.j))
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::generate::Generate;
+use crate::parse::{expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+use std::iter::Sum;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::dec_uint;
+use winnow::combinator::{alt, delimited, opt, repeat, separated};
+use winnow::error::ContextError;
+use z3::Optimize;
+use z3::ast::Int;
+
+pub struct Day10;
+
+/// A machine: its target lights, the counters each button is wired to, and its target joltages.
+pub struct ProblemRow {
+    pub light_map: Vec<bool>,
+    pub buttons: Vec<Vec<u16>>,
+    pub joltages: Vec<u16>,
+}
+
+fn parse_light(input: &mut &str) -> Result<bool> {
+    alt(('.', '#')).map(|c| c == '#').parse_next(input)
+}
+fn parse_light_map(input: &mut &str) -> Result<Vec<bool>> {
+    delimited('[', repeat(1.., parse_light), ']').parse_next(input)
+}
+
+fn parse_u16_vec(input: &mut &str) -> Result<Vec<u16>> {
+    separated(1.., dec_uint::<_, u16, _>, ',').parse_next(input)
+}
+/// A button, wired to some of the machine's `lights`. The indices are read one by one instead of
+/// with `separated`, which would backtrack over a bad one and fail at the comma before it.
+fn parse_button<'s>(lights: usize) -> impl Parser<&'s str, Vec<u16>, ContextError> {
+    let mut light = dec_uint::<_, u16, _>
+        .verify(move |&light| usize::from(light) < lights)
+        .context(expected("index of one of the machine's lights"));
+
+    move |input: &mut &'s str| -> Result<Vec<u16>> {
+        '('.parse_next(input)?;
+        let mut button = vec![light.parse_next(input)?];
+        while opt(',').parse_next(input)?.is_some() {
+            button.push(light.parse_next(input)?);
+        }
+        ')'.parse_next(input)?;
+        Ok(button)
+    }
+}
+
+/// The buttons of a machine with `lights`, one space apart.
+fn parse_buttons<'s>(lights: usize) -> impl Parser<&'s str, Vec<Vec<u16>>, ContextError> {
+    let mut button = parse_button(lights);
+
+    move |input: &mut &'s str| -> Result<Vec<Vec<u16>>> {
+        let mut buttons = vec![button.parse_next(input)?];
+        while input.starts_with(" (") {
+            ' '.parse_next(input)?;
+            buttons.push(button.parse_next(input)?);
+        }
+        Ok(buttons)
+    }
+}
+
+/// One joltage for each of the machine's `lights`.
+fn parse_joltages<'s>(lights: usize) -> impl Parser<&'s str, Vec<u16>, ContextError> {
+    delimited('{', parse_u16_vec, '}')
+        .verify(move |joltages: &Vec<u16>| joltages.len() == lights)
+        .context(expected("one joltage per light"))
+}
+
+fn parse_machine(input: &mut &str) -> Result<ProblemRow> {
+    let light_map = parse_light_map.parse_next(input)?;
+    let lights = light_map.len();
+    let buttons = delimited(' ', parse_buttons(lights), ' ').parse_next(input)?;
+    let joltages = parse_joltages(lights).parse_next(input)?;
+
+    Ok(ProblemRow {
+        light_map,
+        buttons,
+        joltages,
+    })
+}
+
+fn parse_problem_row(input: &mut &str) -> Result<ProblemRow> {
+    parse_machine
+        .context(expected("machine such as `[.##.] (3) (1,3) {3,5,4,7}`"))
+        .parse_next(input)
+}
+
+fn parse_problem(input: &mut &str) -> Result<Vec<ProblemRow>> {
+    lines(parse_problem_row).parse_next(input)
+}
+
+fn button_machine_activation_matrix(buttons: &[Vec<u16>], n_machines: usize) -> Vec<Vec<bool>> {
+    let mut button_machine_matrix: Vec<Vec<bool>> = vec![vec![false; n_machines]; buttons.len()];
+
+    for (i, button) in buttons.iter().enumerate() {
+        for &j in button {
+            button_machine_matrix[i][j as usize] = true;
+        }
+    }
+
+    button_machine_matrix
+}
+
+fn init_z3_variables(n_variables: usize, problem_i: usize) -> Vec<Int> {
+    let mut variables = Vec::with_capacity(n_variables);
+
+    for button_i in 0..n_variables {
+        variables.push(Int::new_const(format!("press_{problem_i}_{button_i}")));
+    }
+    variables
+}
+
+/// Minimizes the total number of button presses, given how the sum of the presses of the
+/// buttons affecting each machine is constrained.
+fn min_button_presses(
+    pr: &ProblemRow,
+    problem_index: usize,
+    machine_constraint: impl Fn(usize, Int) -> z3::ast::Bool,
+) -> Result<u64, AocError> {
+    let button_machine_matrix = button_machine_activation_matrix(&pr.buttons, pr.light_map.len());
+
+    let optimizer = Optimize::new();
+    let button_presses = init_z3_variables(button_machine_matrix.len(), problem_index);
+
+    let total_button_presses = Int::sum(button_presses.iter());
+
+    optimizer.minimize(&total_button_presses);
+
+    for bp in &button_presses {
+        let bp_geq_zero = &bp.ge(0);
+        optimizer.assert(bp_geq_zero);
+    }
+
+    for j in 0..pr.light_map.len() {
+        let button_presses_affecting_this_machine: Vec<&Int> = (0..button_machine_matrix.len())
+            .filter_map(|i| {
+                if button_machine_matrix[i][j] {
+                    Some(&button_presses[i])
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let sum_button_presses = Int::sum(button_presses_affecting_this_machine.into_iter());
+
+        optimizer.assert(&machine_constraint(j, sum_button_presses));
+    }
+
+    match optimizer.check(&[]) {
+        z3::SatResult::Sat => {
+            if let Some(model) = optimizer.get_model()
+                && let Some(presses) = model.eval(&total_button_presses, true)
+                && let Some(presses) = presses.as_u64()
+            {
+                Ok(presses)
+            } else {
+                Err(AocError::invalid(format!(
+                    "could not read the presses for machine {}",
+                    problem_index + 1
+                )))
+            }
+        }
+        z3::SatResult::Unsat | z3::SatResult::Unknown => Err(AocError::invalid(format!(
+            "machine {} cannot be configured",
+            problem_index + 1
+        ))),
+    }
+}
+
+impl Solution for Day10 {
+    type Parsed<'a> = Vec<ProblemRow>;
+
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Vec<ProblemRow>, AocError> {
+        parse_input(parse_problem, input)
+    }
+
+    fn part1(problems: &Vec<ProblemRow>) -> Result<Answer, AocError> {
+        problems
+            .iter()
+            .enumerate()
+            .map(|(i, pr)| {
+                min_button_presses(pr, i, |j, presses| {
+                    presses.modulo(2).eq(i32::from(pr.light_map[j]))
+                })
+            })
+            .sum::<Result<u64, _>>()
+            .map(Answer::from)
+    }
+
+    fn part2(problems: &Vec<ProblemRow>) -> Result<Answer, AocError> {
+        problems
+            .iter()
+            .enumerate()
+            .map(|(i, pr)| min_button_presses(pr, i, |j, presses| presses.eq(pr.joltages[j])))
+            .sum::<Result<u64, _>>()
+            .map(Answer::from)
+    }
+}
+
+solution_fns! {
+    Day10(Vec<ProblemRow>);
+    /// The fewest button presses that set the lights of every machine.
+    part1;
+    /// The fewest button presses that reach the joltages of every machine.
+    part2;
+}
+
+impl Generate for Day10 {
+    /// Machines that can always be configured: the lights are those toggled by some of the
+    /// buttons, and the joltages those reached by pressing each button some number of times.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let lights = rng.range(2..=8) as usize;
+                let mut buttons: Vec<Vec<usize>> = (0..rng.range(2..=lights as u64 + 3))
+                    .map(|_| {
+                        let mut wiring: Vec<usize> =
+                            (0..lights).filter(|_| rng.chance(1, 3)).collect();
+                        if wiring.is_empty() {
+                            wiring.push(rng.below(lights));
+                        }
+                        wiring
+                    })
+                    .collect();
+                // Every counter is wired to some button, so no joltage is stuck at 0
+                for light in 0..lights {
+                    if !buttons.iter().any(|wiring| wiring.contains(&light)) {
+                        let button = rng.below(buttons.len());
+                        let wiring = &mut buttons[button];
+                        wiring.push(light);
+                        wiring.sort_unstable();
+                    }
+                }
+
+                let mut light_map = vec![false; lights];
+                let mut joltages = vec![0; lights];
+                for wiring in &buttons {
+                    let toggled = rng.chance(1, 2);
+                    let presses = rng.range(0..=20);
+                    for &light in wiring {
+                        light_map[light] ^= toggled;
+                        joltages[light] += presses;
+                    }
+                }
+
+                let light_map: String = light_map
+                    .iter()
+                    .map(|&on| if on { '#' } else { '.' })
+                    .collect();
+                let buttons: Vec<String> = buttons
+                    .iter()
+                    .map(|wiring| format!("({})", join(wiring)))
+                    .collect();
+                format!(
+                    "[{light_map}] {} {{{}}}\n",
+                    buttons.join(" "),
+                    join(&joltages)
+                )
+            })
+            .collect()
+    }
+}
+
+fn join<T: ToString>(numbers: &[T]) -> String {
+    numbers
+        .iter()
+        .map(T::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use crate::{test_rejects, test_with_files};
+
+    test_with_files!(Day10, part1, part2);
+
+    test_rejects!(Day10;
+        button_past_the_lights: "[.#] (0) (2) {1,2}\n" => (1, 11),
+        later_index_past_the_lights: "[.#] (0) (1) {1,2}\n[.#] (0,5) {1,2}\n" => (2, 9),
+        joltages_for_other_lights: "[.#] (0) (1) {1}\n" => (1, 14),
+        unknown_light: "[.x] (0) {1,2}\n" => (1, 3),
+    );
+}
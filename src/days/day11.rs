@@ -0,0 +1,199 @@
+use crate::generate::Generate;
+use crate::parse::{expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+use std::collections::{HashMap, HashSet};
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::alpha1;
+use winnow::combinator::{separated, separated_pair};
+
+pub struct Day11;
+
+/// The devices and the devices each one outputs to.
+#[derive(Debug, Clone)]
+pub struct Graph<'s> {
+    mappings: HashMap<&'s str, Vec<&'s str>>,
+}
+
+impl<'s> From<Vec<(&'s str, Vec<&'s str>)>> for Graph<'s> {
+    fn from(value: Vec<(&'s str, Vec<&'s str>)>) -> Self {
+        let mut all_nodes: Vec<&'s str> = Vec::new();
+        let mut mappings: HashMap<&'s str, Vec<&'s str>> = value.into_iter().collect();
+
+        // Ensure all nodes are in mappings
+        for (&initial, destinies) in &mappings {
+            all_nodes.push(initial);
+            all_nodes.extend(destinies.iter());
+        }
+
+        all_nodes.sort_unstable();
+        all_nodes.dedup();
+
+        for &node in &all_nodes {
+            mappings.entry(node).or_default();
+        }
+
+        Graph { mappings }
+    }
+}
+
+impl<'s> Graph<'s> {
+    pub fn children(&self, node: &'s str) -> Vec<&'s str> {
+        self.mappings
+            .get(node)
+            .expect("all nodes should be in mappings")
+            .clone()
+    }
+}
+
+fn parse_spaced_labels<'s>(input: &mut &'s str) -> Result<Vec<&'s str>> {
+    separated(1.., alpha1, ' ').parse_next(input)
+}
+
+fn parse_mapping_line<'s>(input: &mut &'s str) -> Result<(&'s str, Vec<&'s str>)> {
+    separated_pair(alpha1, ": ", parse_spaced_labels)
+        .context(expected("device outputs such as `aaa: you hhh`"))
+        .parse_next(input)
+}
+
+fn parse_graph<'s>(input: &mut &'s str) -> Result<Graph<'s>> {
+    lines(parse_mapping_line)
+        .map(|mappings_vec: Vec<(&'s str, Vec<&'s str>)>| Graph::from(mappings_vec))
+        .parse_next(input)
+}
+
+fn paths_to_other_recursive<'s>(
+    from: &'s str,
+    other: &'s str,
+    graph: &Graph<'s>,
+    n_paths_map: &mut HashMap<&'s str, u64>,
+) -> u64 {
+    if let Some(n) = n_paths_map.get(from) {
+        return *n;
+    }
+
+    let n_paths = if from == other {
+        1
+    } else {
+        graph
+            .children(from)
+            .iter()
+            .map(|&c| paths_to_other_recursive(c, other, graph, n_paths_map))
+            .sum()
+    };
+
+    n_paths_map.insert(from, n_paths);
+
+    n_paths
+}
+
+/// How many paths lead from `from` to `other`, which must not be in a cycle.
+pub fn paths_to_other<'s>(
+    from: &'s str,
+    other: &'s str,
+    graph: &Graph<'s>,
+) -> Result<u64, AocError> {
+    for node in [from, other] {
+        if !graph.mappings.contains_key(node) {
+            return Err(AocError::invalid(format!("no device named `{node}`")));
+        }
+    }
+
+    let mut n_paths_map = HashMap::new();
+    Ok(paths_to_other_recursive(
+        from,
+        other,
+        graph,
+        &mut n_paths_map,
+    ))
+}
+
+impl Solution for Day11 {
+    type Parsed<'a> = Graph<'a>;
+
+    const DAY: u8 = 11;
+
+    fn parse(input: &str) -> Result<Graph<'_>, AocError> {
+        parse_input(parse_graph, input)
+    }
+
+    fn part1(graph: &Graph<'_>) -> Result<Answer, AocError> {
+        paths_to_other("you", "out", graph).map(Answer::from)
+    }
+
+    fn part2(graph: &Graph<'_>) -> Result<Answer, AocError> {
+        let paths = (paths_to_other("svr", "dac", graph)?
+            * paths_to_other("dac", "fft", graph)?
+            * paths_to_other("fft", "out", graph)?)
+            + (paths_to_other("svr", "fft", graph)?
+                * paths_to_other("fft", "dac", graph)?
+                * paths_to_other("dac", "out", graph)?);
+
+        Ok(paths.into())
+    }
+}
+
+solution_fns! {
+    Day11(Graph<'_>);
+    /// How many paths lead from `you` to `out`.
+    part1;
+    /// How many paths lead from `svr` to `out` through both `dac` and `fft`.
+    part2;
+}
+
+impl Generate for Day11 {
+    /// A random DAG from `svr` to `out` through `you`, `dac`, `fft` and `size` other devices,
+    /// where every device only outputs to devices after it, so there are no cycles.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names: HashSet<String> =
+            ["svr", "you", "dac", "fft", "out"].map(String::from).into();
+        let mut middle = vec!["you".to_string(), "dac".to_string(), "fft".to_string()];
+        while middle.len() < size + 3 {
+            let name: String = (0..3)
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect();
+            if names.insert(name.clone()) {
+                middle.push(name);
+            }
+        }
+        rng.shuffle(&mut middle);
+
+        let devices: Vec<&str> = ["svr"]
+            .into_iter()
+            .chain(middle.iter().map(String::as_str))
+            .chain(["out"])
+            .collect();
+        // Every device is an output of some device before it, so all of them can be reached from
+        // `svr`, and gets a few more outputs after it
+        let mut outputs: Vec<Vec<&str>> = vec![Vec::new(); devices.len()];
+        for (i, &device) in devices.iter().enumerate().skip(1) {
+            outputs[rng.below(i)].push(device);
+        }
+        for (i, outputs) in outputs.iter_mut().enumerate().take(devices.len() - 1) {
+            for _ in 0..rng.range(0..=2) {
+                let device = devices[i + 1 + rng.below(devices.len() - i - 1)];
+                if !outputs.contains(&device) {
+                    outputs.push(device);
+                }
+            }
+            if outputs.is_empty() {
+                outputs.push("out");
+            }
+        }
+
+        devices[..devices.len() - 1]
+            .iter()
+            .zip(&outputs)
+            .map(|(device, outputs)| format!("{device}: {}\n", outputs.join(" ")))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use crate::test_with_files;
+
+    test_with_files!(Day11, part1, part2);
+}
@@ -0,0 +1,184 @@
+use crate::generate::Generate;
+use crate::parse::{blank_line, expected, lines, parse_input};
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::{dec_uint, line_ending};
+use winnow::combinator::{alt, separated, separated_pair, seq, terminated};
+
+pub struct Day12;
+
+/// The tiles of a present, `true` where it takes up room.
+pub type Shape = [[bool; 3]; 3];
+
+#[derive(Debug)]
+pub struct Present {
+    pub shape: Shape,
+}
+
+impl Present {
+    pub fn area(&self) -> u64 {
+        self.shape
+            .iter()
+            .flat_map(|row| row.iter())
+            .map(|&c| u64::from(c))
+            .sum()
+    }
+}
+
+/// The room under a tree, and how many of each present must fit in it.
+#[derive(Debug)]
+pub struct Region {
+    pub width: u64,
+    pub height: u64,
+    pub shape_quantities: Vec<u64>,
+}
+
+fn parse_id_collon(input: &mut &str) -> Result<u64> {
+    terminated(dec_uint, ":").parse_next(input)
+}
+
+fn parse_shape_tile(input: &mut &str) -> Result<bool> {
+    alt(('.', '#'))
+        .map(|c| c == '#')
+        .context(expected("`.` or `#`"))
+        .parse_next(input)
+}
+
+fn parse_shape_row(input: &mut &str) -> Result<[bool; 3]> {
+    (parse_shape_tile, parse_shape_tile, parse_shape_tile)
+        .map(|(t1, t2, t3)| [t1, t2, t3])
+        .parse_next(input)
+}
+
+fn parse_shape(input: &mut &str) -> Result<[[bool; 3]; 3]> {
+    seq!(parse_shape_row, _: line_ending, parse_shape_row, _: line_ending, parse_shape_row, _: line_ending)
+        .map(|(r1, r2, r3)| [r1, r2, r3])
+        .parse_next(input)
+}
+
+fn parse_present(input: &mut &str) -> Result<Present> {
+    seq!(Present{_: parse_id_collon, _: line_ending, shape: parse_shape}).parse_next(input)
+}
+
+fn parse_presents(input: &mut &str) -> Result<Vec<Present>> {
+    separated(1.., parse_present, blank_line).parse_next(input)
+}
+
+fn parse_region_dimensions(input: &mut &str) -> Result<(u64, u64)> {
+    separated_pair(dec_uint, 'x', dec_uint).parse_next(input)
+}
+
+fn parse_shape_quantities(input: &mut &str) -> Result<Vec<u64>> {
+    separated(1.., dec_uint::<_, u64, _>, ' ').parse_next(input)
+}
+
+fn parse_region(input: &mut &str) -> Result<Region> {
+    separated_pair(parse_region_dimensions, ": ", parse_shape_quantities)
+        .map(|(dims, quantities)| Region {
+            width: dims.0,
+            height: dims.1,
+            shape_quantities: quantities,
+        })
+        .context(expected("region such as `4x4: 0 0 0 0 2 0`"))
+        .parse_next(input)
+}
+
+fn parse_regions(input: &mut &str) -> Result<Vec<Region>> {
+    lines(parse_region).parse_next(input)
+}
+
+pub struct Problem {
+    pub presents: Vec<Present>,
+    pub regions: Vec<Region>,
+}
+
+fn parse_problem(input: &mut &str) -> Result<Problem> {
+    separated_pair(parse_presents, blank_line, parse_regions)
+        .map(|(presents, regions)| Problem { presents, regions })
+        .parse_next(input)
+}
+
+impl Solution for Day12 {
+    type Parsed<'a> = Problem;
+
+    const DAY: u8 = 12;
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Problem, AocError> {
+        parse_input(parse_problem, input)
+    }
+
+    fn part1(problem: &Problem) -> Result<Answer, AocError> {
+        // Just count if there is enough room in the tree for all the presents
+        Ok(problem
+            .regions
+            .iter()
+            .map(|reg| {
+                reg.shape_quantities
+                    .iter()
+                    .zip(&problem.presents)
+                    .map(|(quantity, present)| present.area() * quantity)
+                    .sum::<u64>()
+                    <= (reg.height * reg.width)
+            })
+            .map(u64::from)
+            .sum::<u64>()
+            .into())
+    }
+
+    fn part2(_problem: &Problem) -> Result<Answer, AocError> {
+        unreachable!("the last day has no second part")
+    }
+}
+
+solution_fns! {
+    Day12(Problem);
+    /// How many regions have room for their presents.
+    part1;
+}
+
+impl Generate for Day12 {
+    /// Six presents, each a random 3x3 shape around a filled center, and `size` regions that
+    /// are sometimes roomy enough for their presents and sometimes not.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let presents: Vec<String> = (0..6)
+            .map(|id| {
+                let shape: String = (0..3)
+                    .map(|i| {
+                        let row: String = (0..3)
+                            .map(|j| match (i, j) {
+                                (1, 1) => '#',
+                                _ if rng.chance(2, 3) => '#',
+                                _ => '.',
+                            })
+                            .collect();
+                        row + "\n"
+                    })
+                    .collect();
+                format!("{id}:\n{shape}")
+            })
+            .collect();
+
+        let regions: String = (0..size)
+            .map(|_| {
+                let (width, height) = (rng.range(4..=50), rng.range(4..=50));
+                let most = width * height / 20;
+                let quantities: Vec<String> =
+                    (0..6).map(|_| rng.range(0..=most).to_string()).collect();
+                format!("{width}x{height}: {}\n", quantities.join(" "))
+            })
+            .collect();
+
+        format!("{}\n{regions}", presents.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day12;
+    use crate::test_with_files;
+
+    test_with_files!(Day12, part1);
+}
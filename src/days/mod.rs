@@ -0,0 +1,102 @@
+use crate::bench::{self, BenchConfig, Timings};
+use crate::generate::Generate;
+use crate::parse::normalize;
+use crate::rng::Rng;
+use crate::stream::{self, Streaming};
+use crate::{Answer, AocError, Solution};
+use std::io::BufRead;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+/// The day `aoc new` starts from, built with the tests so it keeps up with the traits and macros.
+/// As day 0 it has no inputs nor answers, so its own tests are all skipped.
+#[cfg(test)]
+pub mod template;
+
+/// The answers to some parts of a day, in order. A part that fails doesn't stop the others.
+pub type Solutions = Vec<Result<Answer, AocError>>;
+
+/// Solves the given parts of a day reading its input from a reader, one record at a time.
+pub type StreamFn = fn(&mut dyn BufRead, &[u8]) -> Result<Solutions, AocError>;
+
+/// A registered day, callable by the runner.
+pub struct Solver {
+    pub day: u8,
+    pub parts: u8,
+    /// Normalizes and parses the input once, and returns the answers to the given parts.
+    pub solve: fn(&str, &[u8]) -> Result<Solutions, AocError>,
+    /// Times the parse and the given parts separately.
+    pub bench: fn(&str, &[u8], &BenchConfig) -> Result<Timings, AocError>,
+    /// Makes up an input of about the given size.
+    pub generate: fn(&mut Rng, usize) -> String,
+    /// For the days that can be solved without holding their whole input.
+    pub stream: Option<StreamFn>,
+}
+
+impl Solver {
+    const fn of<S: Generate>() -> Self {
+        Solver {
+            day: S::DAY,
+            parts: S::PARTS,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+            generate: S::generate,
+            stream: None,
+        }
+    }
+
+    const fn streaming<S: Generate + Streaming>() -> Self {
+        Solver {
+            stream: Some(stream::solve::<S>),
+            ..Solver::of::<S>()
+        }
+    }
+
+    pub fn has_part(&self, part: u8) -> bool {
+        (1..=self.parts).contains(&part)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solutions, AocError> {
+    let input = normalize(input);
+    let parsed = S::parse(&input)?;
+
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part1(&parsed),
+            2 => S::part2(&parsed),
+            _ => panic!("there is no part {part}"),
+        })
+        .collect())
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver::streaming::<day01::Day01>(),
+    Solver::streaming::<day02::Day02>(),
+    Solver::streaming::<day03::Day03>(),
+    Solver::of::<day04::Day04>(),
+    Solver::streaming::<day05::Day05>(),
+    Solver::of::<day06::Day06>(),
+    Solver::of::<day07::Day07>(),
+    Solver::of::<day08::Day08>(),
+    Solver::of::<day09::Day09>(),
+    Solver::of::<day10::Day10>(),
+    Solver::of::<day11::Day11>(),
+    Solver::of::<day12::Day12>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day)
+}
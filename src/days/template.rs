@@ -0,0 +1,43 @@
+use crate::generate::Generate;
+use crate::rng::Rng;
+use crate::{Answer, AocError, Solution, solution_fns};
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    type Parsed<'a> = &'a str;
+
+    const DAY: u8 = 0;
+
+    fn parse(input: &str) -> Result<&str, AocError> {
+        Ok(input)
+    }
+
+    fn part1(_input: &&str) -> Result<Answer, AocError> {
+        Err(AocError::invalid("part 1 is not solved yet"))
+    }
+
+    fn part2(_input: &&str) -> Result<Answer, AocError> {
+        Err(AocError::invalid("part 2 is not solved yet"))
+    }
+}
+
+solution_fns! {
+    Day00(&str);
+    part1;
+    part2;
+}
+
+impl Generate for Day00 {
+    fn generate(_rng: &mut Rng, _size: usize) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day00;
+    use crate::test_with_files;
+
+    test_with_files!(Day00, part1, part2);
+}
@@ -0,0 +1,148 @@
+use std::error::Error;
+use std::fmt;
+
+/// A position in the puzzle input. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The whole line the position falls in, without its line ending.
+    pub line_text: String,
+}
+
+impl Location {
+    /// Locates the byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Location {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The input does not follow the puzzle's format.
+    Parse {
+        location: Location,
+        /// What the parser was looking for, if it could tell.
+        expected: Option<String>,
+    },
+    /// The input is well formed but breaks one of the puzzle's rules.
+    Invalid {
+        location: Option<Location>,
+        reason: String,
+    },
+}
+
+impl AocError {
+    pub fn invalid(reason: impl Into<String>) -> Self {
+        AocError::Invalid {
+            location: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// An [`AocError::Invalid`] pointing at the byte `offset` of `input`.
+    pub fn invalid_at(input: &str, offset: usize, reason: impl Into<String>) -> Self {
+        AocError::Invalid {
+            location: Some(Location::at(input, offset)),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            AocError::Parse { location, .. } => Some(location),
+            AocError::Invalid { location, .. } => location.as_ref(),
+        }
+    }
+}
+
+/// Writes the offending line with a caret under the column, in the style of compiler errors.
+fn write_snippet(f: &mut fmt::Formatter, location: &Location) -> fmt::Result {
+    let gutter = " ".repeat(location.line.to_string().len());
+
+    writeln!(f)?;
+    writeln!(f, "{gutter} |")?;
+    writeln!(f, "{} | {}", location.line, location.line_text)?;
+    write!(f, "{gutter} | {}^", " ".repeat(location.column - 1))
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse { location, expected } => {
+                write!(
+                    f,
+                    "parse error at line {}, column {}: ",
+                    location.line, location.column
+                )?;
+                match expected {
+                    Some(expected) => write!(f, "expected {expected}")?,
+                    None => write!(f, "unexpected input")?,
+                }
+                write_snippet(f, location)
+            }
+            AocError::Invalid {
+                location: Some(location),
+                reason,
+            } => {
+                write!(
+                    f,
+                    "invalid input at line {}, column {}: {reason}",
+                    location.line, location.column
+                )?;
+                write_snippet(f, location)
+            }
+            AocError::Invalid {
+                location: None,
+                reason,
+            } => write!(f, "invalid input: {reason}"),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AocError, Location};
+
+    #[test]
+    fn location_points_inside_line() {
+        let location = Location::at("L68\nL3x\nR1\n", 6);
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 3);
+        assert_eq!(location.line_text, "L3x");
+    }
+
+    #[test]
+    fn location_at_end_of_input() {
+        let location = Location::at("L68\n", 4);
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 1);
+        assert_eq!(location.line_text, "");
+    }
+
+    #[test]
+    fn parse_error_renders_caret() {
+        let error = AocError::Parse {
+            location: Location::at("L68\nL3x\n", 6),
+            expected: Some("newline".to_string()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, column 3: expected newline\n  |\n2 | L3x\n  |   ^"
+        );
+    }
+}
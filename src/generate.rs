@@ -0,0 +1,29 @@
+//! Random puzzle inputs, in the formats the days' parsers accept.
+
+use crate::Solution;
+use crate::rng::Rng;
+
+/// A day that can make up inputs of its own.
+pub trait Generate: Solution {
+    /// A random input that grows with `size`, roughly the number of lines or items it has.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::SOLVERS;
+    use crate::rng::Rng;
+
+    #[test]
+    fn every_day_parses_its_inputs() {
+        for solver in SOLVERS {
+            for size in [1, 2, 5, 20] {
+                let input = (solver.generate)(&mut Rng::new(size as u64), size);
+                // Solving no parts only parses
+                if let Err(error) = (solver.solve)(&input, &[]) {
+                    panic!("day {:02} rejects its input: {error}\n{input}", solver.day);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Points on a map and the directions to move between them.
+//!
+//! Rows grow downwards and columns to the right, as the puzzles draw their maps. [`Point`] holds
+//! `usize` coordinates by default, for positions in a grid, and `i64` ones for maps that grow in
+//! every direction. Moving is checked either way: a step that would leave the type, or the grid
+//! with [`Point::step_within`], gives `None` instead of wrapping around.
+
+/// One of the eight directions on a map, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Up, right, down and left.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Every direction, diagonals included, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// How a step this way changes the row and the column.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let (di, dj) = self.offset();
+        di != 0 && dj != 0
+    }
+}
+
+/// A row `i` and a column `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = usize> {
+    pub i: T,
+    pub j: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(i: T, j: T) -> Self {
+        Point { i, j }
+    }
+}
+
+impl Point<usize> {
+    /// The point one step away towards `direction`, or `None` above the first row or left of the
+    /// first column.
+    pub fn step(self, direction: Direction) -> Option<Point> {
+        let (di, dj) = direction.offset();
+        Some(Point {
+            i: self.i.checked_add_signed(di)?,
+            j: self.j.checked_add_signed(dj)?,
+        })
+    }
+
+    /// [`step`](Point::step), also giving `None` outside a map of `height` rows and `width`
+    /// columns.
+    pub fn step_within(self, direction: Direction, height: usize, width: usize) -> Option<Point> {
+        self.step(direction)
+            .filter(|point| point.i < height && point.j < width)
+    }
+
+    /// The number of orthogonal steps between the points.
+    pub fn manhattan(self, other: Point) -> usize {
+        self.i.abs_diff(other.i) + self.j.abs_diff(other.j)
+    }
+
+    /// The number of steps between the points when diagonal ones are allowed.
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.i.abs_diff(other.i).max(self.j.abs_diff(other.j))
+    }
+
+    /// The same point with signed coordinates, unless one of them is too large for them.
+    pub fn to_signed(self) -> Option<Point<i64>> {
+        Some(Point {
+            i: self.i.try_into().ok()?,
+            j: self.j.try_into().ok()?,
+        })
+    }
+}
+
+impl Point<i64> {
+    /// The point one step away towards `direction`, or `None` at the limits of `i64`.
+    pub fn step(self, direction: Direction) -> Option<Point<i64>> {
+        let (di, dj) = direction.offset();
+        Some(Point {
+            i: self.i.checked_add(di as i64)?,
+            j: self.j.checked_add(dj as i64)?,
+        })
+    }
+
+    /// The number of orthogonal steps between the points, in a `u128` since it can be twice
+    /// as large as the largest `u64`.
+    pub fn manhattan(self, other: Point<i64>) -> u128 {
+        u128::from(self.i.abs_diff(other.i)) + u128::from(self.j.abs_diff(other.j))
+    }
+
+    /// The number of steps between the points when diagonal ones are allowed.
+    pub fn chebyshev(self, other: Point<i64>) -> u64 {
+        self.i.abs_diff(other.i).max(self.j.abs_diff(other.j))
+    }
+
+    /// The same point with unsigned coordinates, unless one of them is negative.
+    pub fn to_unsigned(self) -> Option<Point> {
+        Some(Point {
+            i: self.i.try_into().ok()?,
+            j: self.j.try_into().ok()?,
+        })
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((i, j): (usize, usize)) -> Point {
+        Point { i, j }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point};
+
+    #[test]
+    fn steps_are_checked() {
+        let corner: Point = Point::new(0, 0);
+        assert_eq!(corner.step(Direction::Down), Some(Point::new(1, 0)));
+        assert_eq!(corner.step(Direction::Left), None);
+        assert_eq!(corner.step(Direction::UpRight), None);
+        assert_eq!(
+            corner.step_within(Direction::Right, 1, 2),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(Point::new(0, 1).step_within(Direction::Right, 1, 2), None);
+
+        let far = Point::new(i64::MAX, -3);
+        assert_eq!(far.step(Direction::Left), Some(Point::new(i64::MAX, -4)));
+        assert_eq!(far.step(Direction::Down), None);
+        assert_eq!(far.to_unsigned(), None);
+    }
+
+    #[test]
+    fn measures_distances() {
+        let (p, q): (Point, Point) = (Point::new(1, 5), Point::new(4, 1));
+        assert_eq!((p.manhattan(q), p.chebyshev(q)), (7, 4));
+
+        let (p, q) = (Point::new(-2i64, 3), Point::new(2, -3));
+        assert_eq!((p.manhattan(q), p.chebyshev(q)), (10, 6));
+
+        let (p, q) = (
+            Point::new(i64::MIN, i64::MIN),
+            Point::new(i64::MAX, i64::MAX),
+        );
+        assert_eq!(p.manhattan(q), 2 * u128::from(u64::MAX));
+        assert_eq!(p.chebyshev(q), u64::MAX);
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+    }
+}
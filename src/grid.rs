@@ -0,0 +1,273 @@
+//! A rectangular grid of tiles, for the days whose input is a map.
+//!
+//! Tiles are stored row after row in a single `Vec`, and found by their [`Point`]: a row and a
+//! column counted from the top left corner.
+
+use crate::geom::{Direction, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A tile that can be drawn as a single character, for showing a grid with `{}`.
+pub trait Tile {
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for `true` and `.` for `false`, as the puzzles draw them.
+impl Tile for bool {
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    tiles: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` by `height` copies of `tile`.
+    pub fn new(width: usize, height: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            tiles: vec![tile; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// The grid with these rows, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If the rows aren't all as wide as the first one.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut tiles = Vec::with_capacity(width * height);
+
+        for (i, row) in rows.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "row {i} is not as wide as the first one");
+            tiles.extend(row);
+        }
+
+        Grid {
+            tiles,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, Point { i, j }: Point) -> Option<usize> {
+        (i < self.height && j < self.width).then(|| i * self.width + j)
+    }
+
+    /// The tile at `position`, or `None` outside the grid.
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.offset(position).map(|offset| &self.tiles[offset])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.tiles[offset])
+    }
+
+    /// Every position of the grid, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| Point { i, j }))
+    }
+
+    /// Every tile with its position, row after row.
+    pub fn tiles(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.tiles)
+    }
+
+    /// The positions one step away from `position` towards `directions`, leaving out those
+    /// outside the grid.
+    fn around(
+        &self,
+        position: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + use<T> {
+        let (width, height) = (self.width, self.height);
+
+        directions
+            .iter()
+            .filter_map(move |&direction| position.step_within(direction, height, width))
+    }
+
+    /// The positions above, right of, below and left of `position`, leaving out those outside the
+    /// grid.
+    pub fn neighbours4(&self, position: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.around(position, &Direction::ORTHOGONAL)
+    }
+
+    /// The eight positions around `position`, diagonals included, leaving out those outside the
+    /// grid.
+    pub fn neighbours8(&self, position: Point) -> impl Iterator<Item = Point> + use<T> {
+        self.around(position, &Direction::ALL)
+    }
+
+    /// The position of the first tile, row after row, that matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.tiles()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(position, _)| position)
+    }
+
+    /// The positions of every tile that matches `predicate`, row after row.
+    pub fn find_all(&self, mut predicate: impl FnMut(&T) -> bool) -> impl Iterator<Item = Point> {
+        self.tiles()
+            .filter(move |(_, tile)| predicate(tile))
+            .map(|(position, _)| position)
+    }
+
+    /// The rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` refuses a width of 0, which a grid without tiles has
+        self.tiles.chunks(self.width.max(1))
+    }
+
+    /// The tiles of column `j`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `j` is outside the grid.
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        let (width, height) = (self.width, self.height);
+        assert!(j < width, "column {j} is outside the {width}x{height} grid");
+        self.tiles.iter().skip(j).step_by(width)
+    }
+
+    /// The columns, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// The grid flipped over its main diagonal, so its rows are this grid's columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            tiles: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    /// A grid of the same size, with `f` applied to each tile and its position.
+    pub fn map<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            tiles: self
+                .tiles()
+                .map(|(position, tile)| f(position, tile))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the {width}x{height} grid"))
+    }
+}
+
+/// One line per row, without a newline after the last one.
+impl<T: Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{}", tile.to_char())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::geom::Point;
+
+    fn digits() -> Grid<char> {
+        Grid::from_rows(vec![vec!['1', '2', '3'], vec!['4', '5', '6']])
+    }
+
+    fn points(points: &[(usize, usize)]) -> Vec<Point> {
+        points.iter().map(|&point| point.into()).collect()
+    }
+
+    #[test]
+    fn finds_tiles_and_neighbours() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(1, 2)), Some(&'6'));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.find(|&c| c > '2'), Some(Point::new(0, 2)));
+        assert_eq!(
+            grid.find_all(|&c| c.to_digit(10).unwrap() % 2 == 0)
+                .collect::<Vec<_>>(),
+            points(&[(0, 1), (1, 0), (1, 2)])
+        );
+
+        let around = |i, j| grid.neighbours4(Point::new(i, j)).collect::<Vec<_>>();
+        assert_eq!(around(0, 0), points(&[(0, 1), (1, 0)]));
+        assert_eq!(around(1, 1), points(&[(0, 1), (1, 2), (1, 0)]));
+        assert_eq!(grid.neighbours8(Point::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn walks_rows_and_columns() {
+        let mut grid = digits();
+        grid[Point::new(0, 0)] = '0';
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['0', '2', '3'], ['4', '5', '6']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "25");
+        assert_eq!(grid.transpose().to_string(), "04\n25\n36");
+        assert_eq!(grid.map(|point, _| point.i == 0).to_string(), "###\n...");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the 3x2 grid")]
+    fn refuses_columns_outside_the_grid() {
+        digits().column(3).for_each(drop);
+    }
+}
@@ -1,37 +1,199 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod check;
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod geom;
+pub mod grid;
+pub mod json;
+pub mod log;
+pub mod memory;
+pub mod parse;
+pub mod pool;
+pub mod ranges;
+pub mod report;
+pub mod rng;
+pub mod scaffold;
+pub mod sha256;
+pub mod store;
+pub mod stream;
+#[doc(hidden)]
+pub mod testing;
+
+/// Runs the Rust examples of the README with the other doctests, so they keep compiling.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+pub struct ReadmeDoctests;
+
+pub use answer::Answer;
+pub use error::AocError;
+
+pub use days::day01;
+pub use days::day02;
+pub use days::day03;
+pub use days::day04;
+pub use days::day05;
+pub use days::day06;
+pub use days::day07;
+pub use days::day08;
+pub use days::day09;
+pub use days::day10;
+pub use days::day11;
+pub use days::day12;
+
+/// A day's puzzle. The input is parsed once and both parts run on the parsed value.
+pub trait Solution {
+    /// Parsed form of the puzzle input, which may borrow from it.
+    type Parsed<'a>;
+
+    const DAY: u8;
+    /// The last day of the event only has one part.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, AocError>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer, AocError>;
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer, AocError>;
+}
+
+/// Exposes a day's [`Solution`] as free functions, `parse` and each part listed with its doc
+/// comment, so the day can be used as a library without the trait.
+///
+/// ```ignore
+/// solution_fns! {
+///     Day05(Problem);
+///     /// How many of the queried ingredient IDs are fresh.
+///     part1;
+///     /// How many IDs the fresh ranges cover.
+///     part2;
+/// }
+/// ```
+#[macro_export]
+macro_rules! solution_fns {
+    ($solution:ident($parsed:ty); $($(#[$doc:meta])* $part:ident;)+) => {
+        /// Parses the puzzle input, which must end its lines with `\n` as
+        /// [`normalize`](crate::parse::normalize) leaves them.
+        pub fn parse(input: &str) -> ::std::result::Result<$parsed, $crate::AocError> {
+            <$solution as $crate::Solution>::parse(input)
+        }
+
+        $(
+            $(#[$doc])*
+            pub fn $part(
+                parsed: &$parsed,
+            ) -> ::std::result::Result<$crate::Answer, $crate::AocError> {
+                <$solution as $crate::Solution>::$part(parsed)
+            }
+        )+
+    };
+}
+
+/// Generates tests checking each part against `answers.toml`: `sample` on the puzzle's sample,
+/// `complete` on the personal input, and one test per extra input listed after a `;`. Listing
+/// `reference` there too adds `matches_reference`, which checks the day against its
+/// [`Reference`](crate::check::Reference) on generated inputs.
+///
+/// ```ignore
+/// test_with_files!(Day01, part1, part2; sample_full_turns, reference);
+/// ```
+///
+/// Inputs are read from `inputs/` when the tests run. Samples are part of the repository, but
+/// personal inputs are not, so `complete` is skipped when `inputs/NN.in` is missing. Tests whose
+/// answer is recorded as unknown are skipped too.
+///
+/// It also generates a test that fails when `answers.toml` records an answer on an input that
+/// none of these tests runs, so a new case can't be forgotten in the list.
 #[macro_export]
 macro_rules! test_with_files {
-    ($day:literal, $solve_func:ident, $expected_sample:expr, $expected_complete:expr) => {
+    ($solution:ty, $($part:ident),+ $(; $($case:ident),+)? $(,)?) => {
+        $crate::test_with_files!(@split $solution, [$($part),+], [], $($($case),+)?);
+    };
+    // `reference` isn't an input but asks for the test against the day's reference solution
+    (@split $solution:ty, [$($part:ident),+], [$($case:ident),*], reference $(, $rest:ident)*) => {
         #[test]
-        fn test_sample() {
-            let input = include_str!(concat!(
-                env!("CARGO_MANIFEST_DIR"),
-                "/inputs/",
-                $day,
-                "_sample.in"
-            ));
-            let result = $solve_func(&input);
-            assert_eq!(result, $expected_sample);
+        fn matches_reference() {
+            $crate::check::assert_matches_reference::<$solution>();
         }
 
+        $crate::test_with_files!(@split $solution, [$($part),+], [$($case),*], $($rest),*);
+    };
+    (
+        @split $solution:ty, [$($part:ident),+], [$($case:ident),*], $next:ident $(, $rest:ident)*
+    ) => {
+        $crate::test_with_files!(
+            @split $solution, [$($part),+], [$($case,)* $next], $($rest),*
+        );
+    };
+    (@split $solution:ty, [$($part:ident),+], [$($case:ident),*] $(,)?) => {
+        $crate::test_with_files!(@parts $solution, [$($case),*], $($part),+);
+
         #[test]
-        fn test_complete() {
-            let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs/", $day, ".in"));
-            let result = $solve_func(&input);
-            assert_eq!(result, $expected_complete);
+        fn tests_every_recorded_input() {
+            $crate::testing::check_cases::<$solution>(&[$(stringify!($case)),*]);
         }
     };
+    (@parts $solution:ty, [$($case:ident),*] $(,)?) => {};
+    (@parts $solution:ty, [$($case:ident),*], $part:ident $(, $rest:ident)*) => {
+        mod $part {
+            use super::*;
+
+            #[test]
+            fn sample() {
+                $crate::testing::check::<$solution>(
+                    $crate::answers::SAMPLE,
+                    $crate::test_with_files!(@number $part),
+                );
+            }
+
+            #[test]
+            fn complete() {
+                $crate::testing::check::<$solution>(
+                    $crate::answers::PERSONAL_INPUT,
+                    $crate::test_with_files!(@number $part),
+                );
+            }
+
+            $(
+                #[test]
+                fn $case() {
+                    $crate::testing::check::<$solution>(
+                        stringify!($case),
+                        $crate::test_with_files!(@number $part),
+                    );
+                }
+            )*
+        }
+
+        $crate::test_with_files!(@parts $solution, [$($case),*], $($rest),*);
+    };
+    (@number part1) => { 1 };
+    (@number part2) => { 2 };
 }
 
+/// Generates a test per malformed input, checking that the day rejects it with an error pointing
+/// at the given line and column, instead of panicking or answering anyway.
+///
+/// ```ignore
+/// test_rejects!(Day07;
+///     ragged_rows: "..S..\n...\n" => (2, 4),
+///     missing_start: ".....\n" => (1, 1),
+/// );
+/// ```
+///
+/// An input the parser accepts must then fail in every part, at that same place.
 #[macro_export]
-macro_rules! parse_input {
-    ($parser:ident, $input:ident) => {{
-        let mut mut_input_ref = $input;
-        match $parser.parse(&mut mut_input_ref) {
-            Ok(res) => res,
-            Err(e) => {
-                eprintln!("{e}");
-                panic!("unparsable input")
-            }
+macro_rules! test_rejects {
+    ($solution:ty; $($case:ident: $input:expr => ($line:expr, $column:expr)),+ $(,)?) => {
+        mod rejects {
+            use super::*;
+
+            $(
+                #[test]
+                fn $case() {
+                    $crate::testing::check_rejects::<$solution>($input, $line, $column);
+                }
+            )+
         }
-    }};
+    };
 }
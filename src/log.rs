@@ -0,0 +1,156 @@
+//! Diagnostics for solvers, written to stderr so stdout only ever carries answers.
+//!
+//! Messages go through [`error!`](crate::error!), [`warn!`](crate::warn!),
+//! [`info!`](crate::info!), [`debug!`](crate::debug!) and [`trace!`](crate::trace!), and are only
+//! formatted when their level is enabled, so expensive dumps such as whole grids cost nothing
+//! otherwise. The level comes from the `AOC_LOG` environment variable, or from the runner's
+//! `--verbose` flag.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The environment variable that sets the level, such as `AOC_LOG=debug`.
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// How much to log, from only errors to everything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                format!(
+                    "unknown log level `{s}`, expected one of error, warn, info, debug or trace"
+                )
+            })
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Logs everything at `level` and below from now on.
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::ALL[usize::from(LEVEL.load(Ordering::Relaxed)) - 1]
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Sets the level from [`ENV_VAR`], if it is set.
+pub fn init_from_env() -> Result<(), String> {
+    if let Ok(value) = std::env::var(ENV_VAR) {
+        set_level(value.parse().map_err(|e| format!("`{ENV_VAR}`: {e}"))?);
+    }
+    Ok(())
+}
+
+/// Writes a message at `level` from `module`. Use the macros, which skip the formatting when the
+/// level is disabled.
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, message: fmt::Arguments) {
+    let module = module.rsplit("::").next().unwrap_or(module);
+    let _ = writeln!(io::stderr().lock(), "[{level} {module}] {message}");
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Logs something that went wrong.
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+/// Logs something suspicious that doesn't stop a run.
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+/// Logs what a run is doing.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Logs intermediate results, for debugging a solver.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Logs large dumps such as whole grids.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Level;
+
+    #[test]
+    fn parses_levels() {
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert_eq!("TRACE".parse(), Ok(Level::Trace));
+        assert!("loud".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn orders_levels_by_detail() {
+        assert!(Level::Error < Level::Warn);
+        assert!(Level::Debug < Level::Trace);
+        assert_eq!(Level::Debug.max(Level::Info), Level::Debug);
+    }
+}
@@ -0,0 +1,197 @@
+//! Counts allocations, to see how much memory a day's parse and parts go through.
+//!
+//! Counting needs the `count-allocs` feature, which swaps the global allocator for one that keeps
+//! per-thread counters on top of the system allocator:
+//!
+//! ```sh
+//! cargo run --release --features count-allocs --bin aoc -- bench 08
+//! ```
+//!
+//! Without it, [`measure`] only runs the closure and counts nothing.
+
+use std::fmt;
+
+/// Whether allocations are counted at all, that is whether the `count-allocs` feature is on.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+/// What some code allocated, on the thread that ran it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// Calls to the allocator, reallocations included.
+    pub count: u64,
+    /// Bytes asked for, with a reallocation counting its new size.
+    pub bytes: u64,
+    /// The most bytes live at once, above what was live before.
+    pub peak: u64,
+}
+
+impl Allocations {
+    pub fn to_json(self) -> String {
+        format!(
+            r#"{{"count": {}, "bytes": {}, "peak_bytes": {}}}"#,
+            self.count, self.bytes, self.peak
+        )
+    }
+}
+
+/// `bytes` with a binary unit, such as `1.5 MiB`.
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        f.pad(&format!("{value:.1} {}", UNITS[unit]))
+    }
+}
+
+/// Runs `f` and counts what it allocates on this thread, or `None` without the `count-allocs`
+/// feature. Measurements don't nest: an inner one resets the peak of the outer one.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Allocations>) {
+    #[cfg(feature = "count-allocs")]
+    {
+        let before = counting::start();
+        let result = f();
+        (result, Some(counting::since(before)))
+    }
+
+    #[cfg(not(feature = "count-allocs"))]
+    (f(), None)
+}
+
+#[cfg(feature = "count-allocs")]
+mod counting {
+    use super::Allocations;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// The counters of one thread. Plain `Cell`s with a const initializer, so reaching them never
+    /// allocates, which would recurse into the allocator.
+    struct Counters {
+        count: Cell<u64>,
+        bytes: Cell<u64>,
+        live: Cell<i64>,
+        peak: Cell<i64>,
+    }
+
+    thread_local! {
+        static COUNTERS: Counters = const {
+            Counters {
+                count: Cell::new(0),
+                bytes: Cell::new(0),
+                live: Cell::new(0),
+                peak: Cell::new(0),
+            }
+        };
+    }
+
+    /// Records `allocated` new bytes and `freed` old ones, counting a call if `allocated` isn't 0.
+    fn record(allocated: usize, freed: usize) {
+        // Fails only while the thread is being torn down, when nobody is measuring anymore
+        let _ = COUNTERS.try_with(|counters| {
+            if allocated > 0 {
+                counters.count.set(counters.count.get() + 1);
+                counters.bytes.set(counters.bytes.get() + allocated as u64);
+            }
+            let live = counters.live.get() + allocated as i64 - freed as i64;
+            counters.live.set(live);
+            counters.peak.set(counters.peak.get().max(live));
+        });
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            record(0, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                record(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    /// The counters when a measurement starts, after resetting the peak to what is live now.
+    pub(super) fn start() -> (u64, u64, i64) {
+        COUNTERS.with(|counters| {
+            counters.peak.set(counters.live.get());
+            (
+                counters.count.get(),
+                counters.bytes.get(),
+                counters.live.get(),
+            )
+        })
+    }
+
+    pub(super) fn since((count, bytes, live): (u64, u64, i64)) -> Allocations {
+        COUNTERS.with(|counters| Allocations {
+            count: counters.count.get() - count,
+            bytes: counters.bytes.get() - bytes,
+            peak: (counters.peak.get() - live).max(0) as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bytes, ENABLED, measure};
+    use std::hint::black_box;
+
+    #[test]
+    fn counts_allocations_when_enabled() {
+        let (sum, allocations) = measure(|| {
+            let first = black_box(vec![0u8; 1000]);
+            drop(first);
+            let second = black_box(vec![1u8; 600]);
+            second.iter().map(|&b| u64::from(b)).sum::<u64>()
+        });
+
+        assert_eq!(sum, 600);
+        assert_eq!(allocations.is_some(), ENABLED);
+        if let Some(allocations) = allocations {
+            assert_eq!(allocations.count, 2);
+            assert_eq!(allocations.bytes, 1600);
+            assert_eq!(allocations.peak, 1000);
+        }
+    }
+
+    #[test]
+    fn shows_bytes_in_binary_units() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.0 MiB");
+    }
+}
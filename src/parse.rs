@@ -0,0 +1,168 @@
+use crate::error::{AocError, Location};
+use crate::grid::Grid;
+use std::borrow::Cow;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::line_ending;
+use winnow::combinator::{alt, eof, repeat, terminated};
+use winnow::error::{ContextError, StrContext, StrContextValue};
+
+/// Brings an input to the shape every parser expects: `\n` line endings, no byte order mark, and
+/// exactly one newline after the last line.
+///
+/// Inputs saved on Windows, or copied without their last newline, then parse like the originals.
+/// Trailing spaces are left alone, since some puzzles align columns with them.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let content = input.trim_end_matches(['\n', '\r']);
+
+    if content.is_empty() {
+        return Cow::Borrowed(content);
+    }
+    if input.len() == content.len() + 1 && input.ends_with('\n') && !content.contains('\r') {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = content.replace("\r\n", "\n");
+    normalized.push('\n');
+    Cow::Owned(normalized)
+}
+
+/// Runs `parser` over the whole input, turning a failure into an [`AocError`] that points at
+/// the offending line and column.
+pub fn parse_input<'s, O>(
+    mut parser: impl Parser<&'s str, O, ContextError>,
+    input: &'s str,
+) -> std::result::Result<O, AocError> {
+    parser.parse(input).map_err(|e| {
+        let expected: Vec<String> = e
+            .inner()
+            .context()
+            .filter_map(|context| match context {
+                StrContext::Expected(value) => Some(value.to_string()),
+                _ => None,
+            })
+            .collect();
+
+        AocError::Parse {
+            location: Location::at(input, e.offset()),
+            expected: (!expected.is_empty()).then(|| expected.join(" or ")),
+        }
+    })
+}
+
+/// Labels a parser with what it expects, for error messages.
+pub fn expected(description: &'static str) -> StrContext {
+    StrContext::Expected(StrContextValue::Description(description))
+}
+
+/// The end of a line: `\n`, `\r\n`, or the end of an input whose last line has no newline.
+pub fn line_end(input: &mut &str) -> Result<()> {
+    alt((line_ending.void(), eof.void()))
+        .context(StrContext::Expected(StrContextValue::CharLiteral('\n')))
+        .parse_next(input)
+}
+
+/// The empty line between two sections of an input.
+pub fn blank_line(input: &mut &str) -> Result<()> {
+    line_ending
+        .void()
+        .context(expected("blank line"))
+        .parse_next(input)
+}
+
+/// Parses a single line of `item`, for inputs that are one line long.
+pub fn line<'s, O>(
+    item: impl Parser<&'s str, O, ContextError>,
+) -> impl Parser<&'s str, O, ContextError> {
+    terminated(item, line_end)
+}
+
+/// Parses one or more lines of `item`, stopping at a blank line or the end of the input. Lines
+/// may end with `\n` or `\r\n`, and the last one needs no line ending at all.
+///
+/// Unlike `repeat(1.., terminated(item, newline))`, a line that starts but does not parse is
+/// reported where it fails, instead of ending the list there and failing further up.
+pub fn lines<'s, O>(
+    mut item: impl Parser<&'s str, O, ContextError>,
+) -> impl Parser<&'s str, Vec<O>, ContextError> {
+    move |input: &mut &'s str| -> Result<Vec<O>> {
+        let mut items = Vec::new();
+
+        loop {
+            items.push(item.parse_next(input)?);
+            line_end.parse_next(input)?;
+
+            if input.is_empty() || input.starts_with('\n') || input.starts_with("\r\n") {
+                return Ok(items);
+            }
+        }
+    }
+}
+
+/// Parses a [`Grid`] of `cell`s, one row per line, which must all be as wide as the first one. A row that
+/// is too short fails where its next cell should be, and one that is too long at its first extra
+/// cell.
+pub fn grid<'s, O>(
+    mut cell: impl Parser<&'s str, O, ContextError>,
+) -> impl Parser<&'s str, Grid<O>, ContextError> {
+    let mut width = None;
+
+    lines(move |input: &mut &'s str| -> Result<Vec<O>> {
+        let row: Vec<O> = match width {
+            None => repeat(1.., cell.by_ref()).parse_next(input)?,
+            Some(width) => repeat(width, cell.by_ref()).parse_next(input)?,
+        };
+        width = Some(row.len());
+        Ok(row)
+    })
+    .map(Grid::from_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blank_line, grid, lines, normalize, parse_input};
+    use std::borrow::Cow;
+    use winnow::ascii::dec_uint;
+    use winnow::token::one_of;
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert!(matches!(normalize("1\n2\n"), Cow::Borrowed("1\n2\n")));
+        assert_eq!(normalize("1\r\n2\r\n"), "1\n2\n");
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+        assert_eq!(normalize("\u{feff}1\n2\n\n\n"), "1\n2\n");
+        assert_eq!(normalize("  1  \r\n"), "  1  \n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn lines_accept_any_line_ending() {
+        let numbers = |input| parse_input(lines(dec_uint::<_, u32, _>), input);
+
+        for input in ["1\n2\n", "1\r\n2\r\n", "1\n2", "1\r\n2"] {
+            assert_eq!(numbers(input).unwrap(), [1, 2], "{input:?}");
+        }
+        assert!(numbers("1 2\n").is_err());
+    }
+
+    #[test]
+    fn grids_reject_ragged_rows() {
+        let cells = |input| parse_input(grid(one_of('0'..='9')), input);
+
+        assert_eq!(cells("12\n34\n").unwrap().to_string(), "12\n34");
+        for (input, column) in [("12\n3\n", 2), ("12\n345\n", 3)] {
+            let location = cells(input).unwrap_err().location().cloned().unwrap();
+            assert_eq!((location.line, location.column), (2, column), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        for input in ["1\n2\n\n3\n", "1\r\n2\r\n\r\n3"] {
+            let sections = (lines(dec_uint::<_, u32, _>), blank_line, lines(dec_uint));
+            let (first, (), second): (_, _, Vec<u32>) = parse_input(sections, input).unwrap();
+            assert_eq!((first, second), (vec![1, 2], vec![3]), "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! A few worker threads for running independent days side by side, with results kept in order.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// One worker per core, or a single one when the number of cores is unknown.
+pub fn default_jobs() -> NonZeroUsize {
+    thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+/// Runs `work` on every item with up to `jobs` workers and hands each result to `done` in the
+/// order of `items`, as soon as it and every result before it are ready.
+///
+/// Once `done` returns an error no more items are started, and the error is returned after the
+/// ones already running finish. With a single job, items run one after the other on the calling
+/// thread.
+pub fn run_ordered<T, R, E>(
+    items: &[T],
+    jobs: NonZeroUsize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(R) -> Result<(), E>,
+) -> Result<(), E>
+where
+    T: Sync,
+    R: Send,
+{
+    let jobs = jobs.get().min(items.len());
+    if jobs <= 1 {
+        return items.iter().try_for_each(|item| done(work(item)));
+    }
+
+    let next = AtomicUsize::new(0);
+    let stopped = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let (next, stopped, work, sender) = (&next, &stopped, &work, sender.clone());
+            scope.spawn(move || {
+                while !stopped.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+                    if sender.send((index, work(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Results that arrived before some earlier one, waiting for their turn
+        let mut waiting = BTreeMap::new();
+        let mut turn = 0;
+
+        for (index, result) in &receiver {
+            waiting.insert(index, result);
+            while let Some(result) = waiting.remove(&turn) {
+                turn += 1;
+                if let Err(error) = done(result) {
+                    stopped.store(true, Ordering::Relaxed);
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_ordered;
+    use std::num::NonZeroUsize;
+    use std::thread;
+    use std::time::Duration;
+
+    fn jobs(n: usize) -> NonZeroUsize {
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    #[test]
+    fn keeps_results_in_order() {
+        let items: Vec<u64> = (0..12).collect();
+        // Later items finish first
+        let slow_square = |&n: &u64| {
+            thread::sleep(Duration::from_millis(12 - n));
+            n * n
+        };
+
+        for n in [1, 4, 32] {
+            let mut results = Vec::new();
+            run_ordered(&items, jobs(n), slow_square, |square| {
+                results.push(square);
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+
+            assert_eq!(results, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn stops_at_the_first_error() {
+        let items: Vec<u32> = (0..100).collect();
+        let mut seen = Vec::new();
+
+        let result = run_ordered(
+            &items,
+            jobs(4),
+            |&n| n,
+            |n| {
+                seen.push(n);
+                if n == 3 { Err(n) } else { Ok(()) }
+            },
+        );
+
+        assert_eq!(result, Err(3));
+        assert_eq!(seen, [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,280 @@
+//! Sets of integers kept as sorted, disjoint ranges, for puzzles about ID ranges.
+//!
+//! A [`RangeSet`] merges ranges that overlap or touch as they come in, so `3-5` and `6-8` are kept
+//! as `3-8`, and every integer is in at most one of its ranges. Ranges are inclusive at both ends,
+//! like the puzzles write them.
+
+use std::cmp::{max, min};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// An integer type a [`RangeSet`] can hold.
+pub trait Integer: Copy + Ord + Debug {
+    /// The next integer, or `None` after the largest one.
+    fn successor(self) -> Option<Self>;
+    /// The previous integer, or `None` before the smallest one.
+    fn predecessor(self) -> Option<Self>;
+    /// How many integers there are from `start` to `end`, both included, which must be in order.
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($int:ty),+) => {
+        $(
+            impl Integer for $int {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn span(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Whether a range ending at `end` overlaps or touches one starting at `start`, when it doesn't
+/// start after it.
+fn touches<T: Integer>(end: T, start: T) -> bool {
+    end.successor().is_none_or(|next| next >= start)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    /// The end of each range, by its start.
+    ranges: BTreeMap<T, T>,
+    /// How many integers the ranges hold together.
+    len: u128,
+}
+
+impl<T: Integer> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet::new()
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet {
+            ranges: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u128 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many disjoint ranges the set is made of.
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// The ranges of the set, in order, none of them touching the next.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// The range of the set that `value` is in.
+    pub fn containing_range(&self, value: T) -> Option<RangeInclusive<T>> {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .filter(|&(_, &end)| value <= end)
+            .map(|(&start, &end)| start..=end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.containing_range(value).is_some()
+    }
+
+    /// Adds the integers of `range`, merging it with the ranges it overlaps or touches. An empty
+    /// range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&before, &before_end)) = self.ranges.range(..=start).next_back()
+            && touches(before_end, start)
+        {
+            self.take(before);
+            start = before;
+            end = max(end, before_end);
+        }
+        while let Some((&after, &after_end)) = self.ranges.range(start..).next()
+            && touches(end, after)
+        {
+            self.take(after);
+            end = max(end, after_end);
+        }
+
+        self.put(start, end);
+    }
+
+    /// Takes the integers of `range` out, splitting the ranges that stick out of it.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&before, &before_end)) = self.ranges.range(..start).next_back()
+            && before_end >= start
+        {
+            self.take(before);
+            // `before` is smaller than `start`, which then has a predecessor
+            self.put(before, start.predecessor().unwrap());
+            self.put_after(end, before_end);
+        }
+        while let Some((&inside, &inside_end)) = self.ranges.range(start..=end).next() {
+            self.take(inside);
+            self.put_after(end, inside_end);
+        }
+    }
+
+    /// Every integer in `self`, in `other` or in both.
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// The integers in both `self` and `other`.
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut intersection = RangeSet::new();
+        let (mut ours, mut theirs) = (self.iter().peekable(), other.iter().peekable());
+
+        while let (Some(our), Some(their)) = (ours.peek(), theirs.peek()) {
+            intersection.insert(max(*our.start(), *their.start())..=min(*our.end(), *their.end()));
+            if our.end() < their.end() {
+                ours.next();
+            } else {
+                theirs.next();
+            }
+        }
+
+        intersection
+    }
+
+    /// The integers in `self` but not in `other`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// The integers of `within` that aren't in the set.
+    pub fn complement(&self, within: RangeInclusive<T>) -> RangeSet<T> {
+        RangeSet::from_iter([within]).difference(self)
+    }
+
+    fn take(&mut self, start: T) {
+        if let Some(end) = self.ranges.remove(&start) {
+            self.len -= T::span(start, end);
+        }
+    }
+
+    fn put(&mut self, start: T, end: T) {
+        self.ranges.insert(start, end);
+        self.len += T::span(start, end);
+    }
+
+    /// Puts back what a range ending at `end` had after `removed_end`, if anything.
+    fn put_after(&mut self, removed_end: T, end: T) {
+        if end > removed_end {
+            // `removed_end` is smaller than `end`, which then has a successor
+            self.put(removed_end.successor().unwrap(), end);
+        }
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<T: Integer> Extend<RangeInclusive<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSet;
+    use std::ops::RangeInclusive;
+
+    fn ranges(set: &RangeSet<i64>) -> Vec<RangeInclusive<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        let mut set: RangeSet<i64> = [10..=14, 3..=5, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(ranges(&set), [3..=5, 10..=20]);
+
+        set.insert(6..=8);
+        set.insert(RangeInclusive::new(9, 2));
+        assert_eq!(ranges(&set), [3..=8, 10..=20]);
+        assert_eq!((set.len(), set.range_count()), (17, 2));
+        assert_eq!(set.containing_range(12), Some(10..=20));
+        assert!(!set.contains(9));
+
+        set.insert(9..=9);
+        assert_eq!(ranges(&set), [3..=20]);
+    }
+
+    #[test]
+    fn removes_by_splitting_ranges() {
+        let mut set: RangeSet<i64> = [0..=10, 20..=30, 40..=50].into_iter().collect();
+        set.remove(5..=22);
+        set.remove(45..=45);
+        assert_eq!(ranges(&set), [0..=4, 23..=30, 40..=44, 46..=50]);
+        assert_eq!(set.len(), 5 + 8 + 5 + 5);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: RangeSet<i64> = [0..=10, 20..=30].into_iter().collect();
+        let b: RangeSet<i64> = [5..=25].into_iter().collect();
+
+        assert_eq!(ranges(&a.union(&b)), [0..=30]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(ranges(&a.complement(-5..=35)), [-5..=-1, 11..=19, 31..=35]);
+    }
+
+    #[test]
+    fn handles_the_ends_of_the_type() {
+        let mut set: RangeSet<u8> = [0..=3, 250..=255].into_iter().collect();
+        set.insert(4..=249);
+        assert_eq!(set.len(), 256);
+
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1..=254]);
+        assert_eq!(set.complement(0..=255).len(), 2);
+    }
+}
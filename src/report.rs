@@ -0,0 +1,223 @@
+//! The results of running every day, as a Markdown table for the wiki and as JSON for scripts.
+
+use crate::answers::Expected;
+use crate::{Answer, json};
+use std::fmt;
+use std::time::Duration;
+
+/// What running one part produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    /// The day returned an error.
+    Failed(String),
+    /// The day panicked, with the panic message.
+    Panicked(String),
+    /// There was no input to run it on.
+    MissingInput,
+}
+
+/// How an answer compares to the recorded one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The answer is wrong, as `answers.toml` records it to be.
+    KnownFailure,
+    /// Nobody has confirmed the answer yet.
+    Unknown,
+}
+
+impl Status {
+    /// The status of `outcome` against the `expected` answer, or `None` if the part didn't run.
+    pub fn of(outcome: &Outcome, expected: Option<&Expected>) -> Option<Status> {
+        match (outcome, expected) {
+            (Outcome::MissingInput, _) => None,
+            // A known failure that now passes must have its marker taken out
+            (
+                Outcome::Solved(answer),
+                Some(Expected::KnownFailure {
+                    answer: expected, ..
+                }),
+            ) if answer == expected => Some(Status::Fail),
+            (_, Some(Expected::KnownFailure { .. })) => Some(Status::KnownFailure),
+            (Outcome::Failed(_) | Outcome::Panicked(_), _) => Some(Status::Fail),
+            (Outcome::Solved(answer), Some(Expected::Known(expected))) if answer == expected => {
+                Some(Status::Pass)
+            }
+            (Outcome::Solved(_), Some(Expected::Known(_))) => Some(Status::Fail),
+            (Outcome::Solved(_), Some(Expected::Unknown) | None) => Some(Status::Unknown),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::KnownFailure => "known failure",
+            Status::Unknown => "unknown",
+        })
+    }
+}
+
+/// One part of one day, run on its own.
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub outcome: Outcome,
+    pub status: Option<Status>,
+    /// Wall-clock time of parsing the input and solving the part.
+    pub time: Option<Duration>,
+}
+
+/// Keeps a cell on one line and out of the way of the table's `|` separators.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Renders `rows` as a Markdown table.
+pub fn markdown(rows: &[Row]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Input | Answer | Match | Time | Panicked |\n\
+         |----:|-----:|-------|--------|-------|-----:|----------|\n",
+    );
+
+    for row in rows {
+        let answer = match &row.outcome {
+            Outcome::Solved(answer) => format!("`{answer}`"),
+            Outcome::Failed(error) => format!("error: {error}"),
+            Outcome::Panicked(message) => format!("panic: {message}"),
+            Outcome::MissingInput => "missing input".to_string(),
+        };
+
+        table += &format!(
+            "| {:02} | {} | `{}` | {} | {} | {} | {} |\n",
+            row.day,
+            row.part,
+            markdown_cell(&row.input),
+            markdown_cell(&answer),
+            row.status
+                .map_or("-".to_string(), |status| status.to_string()),
+            row.time
+                .map_or("-".to_string(), |time| format!("{time:.1?}")),
+            if matches!(row.outcome, Outcome::Panicked(_)) {
+                "yes"
+            } else {
+                "no"
+            },
+        );
+    }
+
+    table
+}
+
+/// Renders `rows` as JSON, with times in nanoseconds.
+pub fn to_json(rows: &[Row]) -> String {
+    let rows: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let (answer, error) = match &row.outcome {
+                Outcome::Solved(answer) => (json::string(&answer.to_string()), "null".to_string()),
+                Outcome::Failed(error) | Outcome::Panicked(error) => {
+                    ("null".to_string(), json::string(error))
+                }
+                Outcome::MissingInput => ("null".to_string(), json::string("missing input")),
+            };
+
+            format!(
+                r#"    {{"day": {}, "part": {}, "input": {}, "answer": {answer}, "error": {error}, "status": {}, "time_ns": {}, "panicked": {}}}"#,
+                row.day,
+                row.part,
+                json::string(&row.input),
+                row.status
+                    .map_or("null".to_string(), |status| json::string(&status.to_string())),
+                row.time
+                    .map_or("null".to_string(), |time| time.as_nanos().to_string()),
+                matches!(row.outcome, Outcome::Panicked(_)),
+            )
+        })
+        .collect();
+
+    format!("{{\n  \"parts\": [\n{}\n  ]\n}}\n", rows.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Outcome, Row, Status, markdown, to_json};
+    use crate::Answer;
+    use crate::answers::Expected;
+    use std::time::Duration;
+
+    fn rows() -> Vec<Row> {
+        let row = |part, outcome: Outcome, status: Option<Status>| Row {
+            day: 7,
+            part,
+            input: "inputs/07.in".to_string(),
+            time: status.map(|_| Duration::from_micros(1500)),
+            outcome,
+            status,
+        };
+
+        vec![
+            row(1, Outcome::Solved(Answer::from(21)), Some(Status::Pass)),
+            row(
+                2,
+                Outcome::Panicked("a | b".to_string()),
+                Some(Status::Fail),
+            ),
+            row(3, Outcome::MissingInput, None),
+        ]
+    }
+
+    #[test]
+    fn compares_with_the_recorded_answer() {
+        let solved = Outcome::Solved(Answer::from(21));
+        let known = |answer: i64| Expected::Known(Answer::from(answer));
+
+        assert_eq!(Status::of(&solved, Some(&known(21))), Some(Status::Pass));
+        assert_eq!(Status::of(&solved, Some(&known(40))), Some(Status::Fail));
+        assert_eq!(
+            Status::of(&solved, Some(&Expected::Unknown)),
+            Some(Status::Unknown)
+        );
+        assert_eq!(Status::of(&solved, None), Some(Status::Unknown));
+
+        let known_failure = |answer: i64| Expected::KnownFailure {
+            answer: Answer::from(answer),
+            reason: "heuristic".to_string(),
+        };
+        assert_eq!(
+            Status::of(&solved, Some(&known_failure(40))),
+            Some(Status::KnownFailure)
+        );
+        assert_eq!(
+            Status::of(&solved, Some(&known_failure(21))),
+            Some(Status::Fail)
+        );
+        assert_eq!(Status::of(&Outcome::MissingInput, Some(&known(21))), None);
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let table = markdown(&rows());
+
+        assert!(table.contains("| 07 | 1 | `inputs/07.in` | `21` | pass | 1.5ms | no |\n"));
+        assert!(
+            table.contains("| 07 | 2 | `inputs/07.in` | panic: a \\| b | FAIL | 1.5ms | yes |\n")
+        );
+        assert!(table.contains("| 07 | 3 | `inputs/07.in` | missing input | - | - | no |\n"));
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = to_json(&rows());
+
+        assert!(json.contains(r#""part": 1, "input": "inputs/07.in", "answer": "21", "error": null, "status": "pass", "time_ns": 1500000, "panicked": false"#));
+        assert!(json.contains(r#""answer": null, "error": "a | b", "status": "FAIL", "time_ns": 1500000, "panicked": true"#));
+        assert!(json.contains(r#""error": "missing input", "status": null, "time_ns": null"#));
+    }
+}
@@ -0,0 +1,87 @@
+//! A small deterministic random number generator, so generated inputs can be reproduced from
+//! their seed on any machine.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64: tiny, fast, and good enough for test inputs. Not for anything cryptographic.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "empty range {low}..={high}");
+
+        match (high - low).checked_add(1) {
+            // Multiplying keeps the bias negligible for the small spans inputs use
+            Some(span) => low + ((u128::from(self.next_u64()) * u128::from(span)) >> 64) as u64,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index below `len`, which must not be zero.
+    pub fn below(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(1..=denominator) <= numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Puts `items` in a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn is_reproducible() {
+        let draws = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.range(1..=6)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(draws(2025), draws(2025));
+        assert_ne!(draws(2025), draws(2026));
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+            assert_eq!(rng.range(7..=7), 7);
+            assert!(rng.below(3) < 3);
+        }
+        assert!(!rng.chance(0, 4));
+        assert!(rng.chance(4, 4));
+        rng.range(0..=u64::MAX);
+    }
+}
@@ -0,0 +1,195 @@
+//! The edits `aoc new` makes to start a day: its module, its registration, its export and its
+//! answers.
+
+/// The module for `day`, made from `template` by renaming `Day00` and setting `DAY`.
+pub fn day_module(template: &str, day: u8) -> String {
+    template
+        .replace("Day00", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+/// The day a line such as `pub mod day07;` or `Solver::of::<day07::Day07>(),` refers to.
+fn day_after(line: &str, prefix: &str) -> Option<u8> {
+    let digits = line.trim_start().strip_prefix(prefix)?.get(..2)?;
+    digits
+        .bytes()
+        .all(|b| b.is_ascii_digit())
+        .then(|| digits.parse().ok())?
+}
+
+/// Inserts `new_line` among the lines starting with one of `prefixes`, keeping them ordered by
+/// day.
+fn insert_ordered(
+    text: &str,
+    prefixes: &[&str],
+    day: u8,
+    new_line: &str,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let day = prefixes.iter().find_map(|prefix| day_after(line, prefix))?;
+            Some((i, day))
+        })
+        .collect();
+
+    let position = match days.iter().rfind(|&&(_, other)| other < day) {
+        Some(&(i, _)) => i + 1,
+        None => days.first().map(|&(i, _)| i).ok_or_else(|| {
+            format!(
+                "no `{}NN` line to register day {day:02} next to",
+                prefixes[0]
+            )
+        })?,
+    };
+    lines.insert(position, new_line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// `days/mod.rs` with `day` declared and added to the solvers the runner knows.
+pub fn register(days_mod: &str, day: u8) -> Result<String, String> {
+    if days_mod
+        .lines()
+        .any(|line| day_after(line, "pub mod day") == Some(day))
+    {
+        return Err(format!("day {day:02} is already registered"));
+    }
+
+    let declaration = format!("pub mod day{day:02};");
+    let days_mod = insert_ordered(days_mod, &["pub mod day"], day, &declaration)?;
+    insert_ordered(
+        &days_mod,
+        &["Solver::of::<day", "Solver::streaming::<day"],
+        day,
+        &format!("    Solver::of::<day{day:02}::Day{day:02}>(),"),
+    )
+}
+
+/// `lib.rs` with `day` re-exported at the root of the crate, as `aoc_2025::dayNN`.
+pub fn export(lib: &str, day: u8) -> Result<String, String> {
+    insert_ordered(
+        lib,
+        &["pub use days::day"],
+        day,
+        &format!("pub use days::day{day:02};"),
+    )
+}
+
+/// The answers file with a section for `day`, where every answer is still unknown.
+pub fn add_answers(answers: &str, day: u8) -> Result<String, String> {
+    let section_day = |line: &str| day_after(line, "[day");
+    if answers.lines().any(|line| section_day(line) == Some(day)) {
+        return Err(format!("answers for day {day:02} are already recorded"));
+    }
+
+    let section = format!(
+        "[day{day:02}]\n\
+         sample.part1 = \"unknown\"\n\
+         sample.part2 = \"unknown\"\n\
+         input.part1 = \"unknown\"\n\
+         input.part2 = \"unknown\"\n"
+    );
+
+    let next_section = answers
+        .lines()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len() + 1;
+            Some((start, line))
+        })
+        .find(|&(_, line)| section_day(line).is_some_and(|other| other > day));
+
+    Ok(match next_section {
+        Some((start, _)) => format!("{}{section}\n{}", &answers[..start], &answers[start..]),
+        None => format!("{}\n\n{section}", answers.trim_end()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_answers, day_module, export, register};
+    use crate::answers::{Answers, Expected, SAMPLE};
+
+    const DAYS_MOD: &str = "pub mod day01;
+pub mod day03;
+
+pub const SOLVERS: &[Solver] = &[
+    Solver::of::<day01::Day01>(),
+    Solver::streaming::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn renames_the_template() {
+        let module = day_module("pub struct Day00;\n    const DAY: u8 = 0;\n", 13);
+        assert_eq!(module, "pub struct Day13;\n    const DAY: u8 = 13;\n");
+
+        let module = day_module(include_str!("days/template.rs"), 13);
+        assert!(module.contains("const DAY: u8 = 13;"), "{module}");
+        assert!(!module.contains("Day00"), "{module}");
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        assert_eq!(
+            register(DAYS_MOD, 2).unwrap(),
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const SOLVERS: &[Solver] = &[
+    Solver::of::<day01::Day01>(),
+    Solver::of::<day02::Day02>(),
+    Solver::streaming::<day03::Day03>(),
+];
+"
+        );
+        assert!(register(DAYS_MOD, 4).unwrap().contains(
+            "pub mod day03;\npub mod day04;\n\
+             \n\
+             pub const SOLVERS: &[Solver] = &[\n    \
+             Solver::of::<day01::Day01>(),\n    \
+             Solver::streaming::<day03::Day03>(),\n    \
+             Solver::of::<day04::Day04>(),\n"
+        ));
+        assert!(register(DAYS_MOD, 3).is_err());
+    }
+
+    #[test]
+    fn exports_days_in_order() {
+        let lib =
+            "pub use error::AocError;\n\npub use days::day01;\npub use days::day03;\n\npub trait";
+
+        assert_eq!(
+            export(lib, 2).unwrap(),
+            "pub use error::AocError;\n\npub use days::day01;\npub use days::day02;\n\
+             pub use days::day03;\n\npub trait\n"
+        );
+        assert!(
+            export(lib, 4)
+                .unwrap()
+                .contains("day03;\npub use days::day04;\n\n")
+        );
+    }
+
+    #[test]
+    fn adds_unknown_answers() {
+        let answers = "# header\n\n[day01]\nsample.part1 = 3\n\n[day03]\nsample.part1 = 1\n";
+
+        let added = add_answers(answers, 2).unwrap();
+        assert!(added.contains("sample.part1 = 3\n\n[day02]\n"));
+        assert!(added.contains("input.part2 = \"unknown\"\n\n[day03]\n"));
+        assert!(matches!(
+            Answers::parse(&added).unwrap().get(2, SAMPLE, 1),
+            Some(Expected::Unknown)
+        ));
+
+        let appended = add_answers(answers, 4).unwrap();
+        assert!(appended.contains("sample.part1 = 1\n\n[day04]\n"));
+        assert!(appended.ends_with("input.part2 = \"unknown\"\n"));
+        assert!(add_answers(answers, 3).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//! The checksums of the inputs in `inputs/`, taken when each was added with `aoc input add`, to
+//! notice when the file behind a recorded answer changes.
+//!
+//! The checksums are split over two files like the inputs themselves: those of the samples are
+//! kept in the repository, and those of personal inputs in a file git ignores, so that adding
+//! one's own inputs never touches a shared file.
+
+use crate::answers::{self, PERSONAL_INPUT, SAMPLE};
+use crate::parse::{expected, lines, normalize, parse_input};
+use crate::{AocError, sha256, warn};
+use std::collections::BTreeMap;
+use winnow::Parser;
+use winnow::Result;
+use winnow::ascii::{digit1, line_ending};
+use winnow::combinator::{opt, preceded, seq};
+use winnow::token::take_while;
+
+/// The checksums of the samples, in `inputs/`.
+pub const STORE_FILE: &str = "checksums.tsv";
+
+/// The checksums of every other input, in `inputs/`.
+pub const LOCAL_STORE_FILE: &str = "checksums.local.tsv";
+
+/// Both files of the store.
+pub const STORE_FILES: [&str; 2] = [STORE_FILE, LOCAL_STORE_FILE];
+
+/// The store file that keeps the checksum of the input named `name`.
+pub fn store_file(name: &str) -> &'static str {
+    if answers::is_sample(name) {
+        STORE_FILE
+    } else {
+        LOCAL_STORE_FILE
+    }
+}
+
+const HEADER: &str = "# day\tinput\tsha256";
+
+/// The SHA-256 of every input added to `inputs/`, keyed by day and input name.
+#[derive(Debug, Default)]
+pub struct Store {
+    checksums: BTreeMap<(u8, String), String>,
+}
+
+/// How an input compares to the one that was added.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Unchanged,
+    Changed,
+    /// The input was never added to the store.
+    Unrecorded,
+}
+
+/// The SHA-256 of an input, after [`normalize`], so line endings alone don't count as a change.
+pub fn checksum(input: &str) -> String {
+    sha256::hex_digest(normalize(input).as_bytes())
+}
+
+/// The name of an input from the options of `aoc input`: `sample`, `sample_<name>`, `<name>`,
+/// or the personal input when neither is given.
+pub fn input_name(sample: bool, name: Option<&str>) -> std::result::Result<String, String> {
+    if let Some(name) = name
+        && (name.is_empty()
+            || !name
+                .chars()
+                .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-')))
+    {
+        return Err(format!(
+            "invalid input name `{name}`, use lowercase letters, digits, `_` and `-`"
+        ));
+    }
+
+    Ok(match (sample, name) {
+        (true, Some(name)) if answers::is_sample(name) => name.to_string(),
+        (true, Some(name)) => format!("{SAMPLE}_{name}"),
+        (true, None) => SAMPLE.to_string(),
+        (false, Some(name)) => name.to_string(),
+        (false, None) => PERSONAL_INPUT.to_string(),
+    })
+}
+
+/// The day and input name of a file in `inputs/`, the reverse of [`answers::input_file`].
+pub fn parse_file_name(file: &str) -> Option<(u8, String)> {
+    let stem = file.strip_suffix(".in")?;
+    let (day, name) = match stem.split_once('_') {
+        Some((day, name)) if !name.is_empty() => (day, name),
+        Some(_) => return None,
+        None => (stem, PERSONAL_INPUT),
+    };
+
+    if day.len() != 2 || !day.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((day.parse().ok()?, name.to_string()))
+}
+
+fn parse_entry(input: &mut &str) -> Result<(u8, String, String)> {
+    seq!(
+        digit1.parse_to(),
+        _: '\t',
+        take_while(1.., |c: char| !c.is_whitespace()).map(str::to_string),
+        _: '\t',
+        take_while(64, ('0'..='9', 'a'..='f'))
+            .map(str::to_string)
+            .context(expected("SHA-256 in hex")),
+    )
+    .parse_next(input)
+}
+
+fn parse_store(input: &mut &str) -> Result<Vec<(u8, String, String)>> {
+    preceded(
+        (HEADER.context(expected("store header")), line_ending),
+        opt(lines(parse_entry)).map(Option::unwrap_or_default),
+    )
+    .parse_next(input)
+}
+
+impl Store {
+    pub fn parse(input: &str) -> std::result::Result<Self, AocError> {
+        let checksums = parse_input(parse_store, input)?
+            .into_iter()
+            .map(|(day, name, checksum)| ((day, name), checksum))
+            .collect();
+
+        Ok(Store { checksums })
+    }
+
+    /// Adds the checksums of another store file.
+    pub fn merge(&mut self, other: Store) {
+        self.checksums.extend(other.checksums);
+    }
+
+    /// The contents of `file`, one of the [`STORE_FILES`], with the checksums it keeps.
+    pub fn to_tsv(&self, file: &str) -> String {
+        let mut tsv = format!("{HEADER}\n");
+        for ((day, name), checksum) in &self.checksums {
+            if store_file(name) == file {
+                tsv += &format!("{day:02}\t{name}\t{checksum}\n");
+            }
+        }
+        tsv
+    }
+
+    /// Records the checksum of `input` as the one named `name` for `day`.
+    pub fn record(&mut self, day: u8, name: &str, input: &str) {
+        self.checksums
+            .insert((day, name.to_string()), checksum(input));
+    }
+
+    pub fn get(&self, day: u8, name: &str) -> Option<&str> {
+        self.checksums
+            .get(&(day, name.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Every recorded input, by day and then by name.
+    pub fn entries(&self) -> impl Iterator<Item = (u8, &str, &str)> {
+        self.checksums
+            .iter()
+            .map(|((day, name), checksum)| (*day, name.as_str(), checksum.as_str()))
+    }
+
+    pub fn check(&self, day: u8, name: &str, input: &str) -> Check {
+        match self.get(day, name) {
+            Some(recorded) if recorded == checksum(input) => Check::Unchanged,
+            Some(_) => Check::Changed,
+            None => Check::Unrecorded,
+        }
+    }
+
+    /// Logs a warning if `input` is not the file that was added, since the answers recorded for
+    /// it may not hold anymore.
+    pub fn warn_if_changed(&self, day: u8, name: &str, input: &str) {
+        if self.check(day, name, input) == Check::Changed {
+            warn!(
+                "`inputs/{}` changed since it was added, its recorded answers may be stale",
+                answers::input_file(day, name)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Check, LOCAL_STORE_FILE, STORE_FILE, Store, input_name, parse_file_name};
+
+    #[test]
+    fn names_inputs() {
+        assert_eq!(input_name(false, None).unwrap(), "input");
+        assert_eq!(input_name(true, None).unwrap(), "sample");
+        assert_eq!(
+            input_name(true, Some("full_turns")).unwrap(),
+            "sample_full_turns"
+        );
+        assert_eq!(input_name(false, Some("alice")).unwrap(), "alice");
+        assert!(input_name(false, Some("../alice")).is_err());
+
+        assert_eq!(parse_file_name("05.in"), Some((5, "input".to_string())));
+        assert_eq!(
+            parse_file_name("11_2_sample.in"),
+            Some((11, "2_sample".to_string()))
+        );
+        assert_eq!(parse_file_name("checksums.tsv"), None);
+        assert_eq!(parse_file_name("5_sample.in"), None);
+    }
+
+    #[test]
+    fn notices_changed_inputs() {
+        let mut store = Store::default();
+        store.record(5, "input", "3-5\n10-14\n\n1\n5\n");
+
+        let store = Store::parse(&store.to_tsv(LOCAL_STORE_FILE)).unwrap();
+        assert_eq!(
+            store.check(5, "input", "3-5\r\n10-14\r\n\r\n1\r\n5"),
+            Check::Unchanged
+        );
+        assert_eq!(
+            store.check(5, "input", "3-5\n10-14\n\n1\n6\n"),
+            Check::Changed
+        );
+        assert_eq!(store.check(5, "alice", "3-5\n"), Check::Unrecorded);
+    }
+
+    #[test]
+    fn keeps_personal_checksums_out_of_the_shared_file() {
+        let mut store = Store::default();
+        store.record(5, "sample", "3-5\n\n1\n");
+        store.record(5, "input", "3-5\n10-14\n\n1\n5\n");
+        store.record(5, "alice", "1-2\n\n1\n");
+
+        let shared = Store::parse(&store.to_tsv(STORE_FILE)).unwrap();
+        let mut merged = Store::parse(&store.to_tsv(LOCAL_STORE_FILE)).unwrap();
+        assert_eq!(
+            shared
+                .entries()
+                .map(|(_, name, _)| name)
+                .collect::<Vec<_>>(),
+            ["sample"]
+        );
+
+        merged.merge(shared);
+        assert_eq!(merged.entries().count(), 3);
+    }
+}
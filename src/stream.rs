@@ -0,0 +1,204 @@
+//! Solving a day one record at a time from a reader, for inputs too big to hold in memory.
+//!
+//! [`Solution::parse`] builds the whole parsed input before any part runs. A day whose answers
+//! can be worked out record by record can also implement [`Streaming`], and [`solve`] then reads
+//! its input through a single reused buffer, keeping only the day's running state.
+
+use crate::days::Solutions;
+use crate::error::Location;
+use crate::{Answer, AocError, Solution};
+use std::io::BufRead;
+
+/// A day that can be solved from its records one at a time.
+pub trait Streaming: Solution {
+    /// What the day keeps between records, such as running totals.
+    type State: Default;
+
+    /// The byte that ends a record: lines for most days, or `,` for an input that is one long
+    /// list. Errors in a record that doesn't start a line point into the record itself.
+    const SEPARATOR: u8 = b'\n';
+
+    /// Takes in the next record, without its separator or line ending. Blank lines are records
+    /// too, except for those at the end of the input, which are dropped like [`normalize`] does.
+    ///
+    /// [`normalize`]: crate::parse::normalize
+    fn feed(state: &mut Self::State, record: &str) -> Result<(), AocError>;
+
+    /// The answer to `part`, once every record has been fed.
+    fn finish(state: &Self::State, part: u8) -> Result<Answer, AocError>;
+}
+
+/// Moves an error found in a record to where the record starts in the whole input.
+fn relocate(error: AocError, line: usize) -> AocError {
+    let shift = |location: Location| Location {
+        line: location.line + line - 1,
+        ..location
+    };
+
+    match error {
+        AocError::Parse { location, expected } => AocError::Parse {
+            location: shift(location),
+            expected,
+        },
+        AocError::Invalid { location, reason } => AocError::Invalid {
+            location: location.map(shift),
+            reason,
+        },
+    }
+}
+
+/// Solves `parts` of `S` reading its input from `reader`, in memory that grows with the longest
+/// record and the day's state rather than with the input.
+pub fn solve<S: Streaming>(reader: &mut dyn BufRead, parts: &[u8]) -> Result<Solutions, AocError> {
+    let mut state = S::State::default();
+    let mut buffer = Vec::new();
+    let (mut line, mut records) = (1, 0);
+    // Blank lines are only fed once a record follows them
+    let mut blank_lines = 0;
+
+    loop {
+        buffer.clear();
+        let read = reader
+            .read_until(S::SEPARATOR, &mut buffer)
+            .map_err(|e| AocError::invalid(format!("could not read line {line}: {e}")))?;
+        if read == 0 {
+            break;
+        }
+
+        let ends_line = buffer.ends_with(b"\n");
+        let mut record = std::str::from_utf8(&buffer)
+            .map_err(|_| AocError::invalid(format!("line {line} is not valid UTF-8")))?;
+        if records == 0 && blank_lines == 0 {
+            record = record.strip_prefix('\u{feff}').unwrap_or(record);
+        }
+        let record = record
+            .strip_suffix(char::from(S::SEPARATOR))
+            .unwrap_or(record)
+            .trim_end_matches(['\n', '\r']);
+
+        if record.is_empty() && S::SEPARATOR == b'\n' {
+            blank_lines += 1;
+        } else {
+            for blank_line in 0..blank_lines {
+                S::feed(&mut state, "")
+                    .map_err(|e| relocate(e, line - blank_lines + blank_line))?;
+            }
+            blank_lines = 0;
+            S::feed(&mut state, record).map_err(|e| relocate(e, line))?;
+            records += 1;
+        }
+
+        if ends_line {
+            line += 1;
+        }
+    }
+
+    // An empty input gets the error the day gives for a missing first record
+    if records == 0 {
+        S::feed(&mut state, "")?;
+    }
+
+    Ok(parts.iter().map(|&part| S::finish(&state, part)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Streaming;
+    use crate::days::{SOLVERS, Solutions};
+    use crate::parse::parse_input;
+    use crate::rng::Rng;
+    use crate::{Answer, AocError, Solution};
+    use winnow::ascii::dec_uint;
+
+    /// Sums numbers, one per line, and counts the blank lines between them.
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed<'a> = ();
+
+        const DAY: u8 = 0;
+
+        fn parse(_input: &str) -> Result<(), AocError> {
+            Ok(())
+        }
+
+        fn part1(_parsed: &()) -> Result<Answer, AocError> {
+            unreachable!()
+        }
+
+        fn part2(_parsed: &()) -> Result<Answer, AocError> {
+            unreachable!()
+        }
+    }
+
+    impl Streaming for Sum {
+        type State = (u64, u64);
+
+        fn feed((sum, blank_lines): &mut (u64, u64), record: &str) -> Result<(), AocError> {
+            if record.is_empty() && *sum > 0 {
+                *blank_lines += 1;
+            } else {
+                *sum += parse_input(dec_uint::<_, u64, _>, record)?;
+            }
+            Ok(())
+        }
+
+        fn finish(&(sum, blank_lines): &(u64, u64), part: u8) -> Result<Answer, AocError> {
+            Ok(if part == 1 { sum } else { blank_lines }.into())
+        }
+    }
+
+    fn solve(input: &str) -> Result<Solutions, AocError> {
+        super::solve::<Sum>(&mut input.as_bytes(), &[1, 2])
+    }
+
+    #[test]
+    fn feeds_lines_like_normalized_input() {
+        let answers: Vec<String> = solve("\u{feff}1\r\n2\r\n\r\n3\n\n\n")
+            .unwrap()
+            .into_iter()
+            .map(|answer| answer.unwrap().to_string())
+            .collect();
+
+        assert_eq!(answers, ["6", "1"]);
+    }
+
+    #[test]
+    fn points_errors_at_their_line() {
+        let error = solve("1\n2\n\n3x\n").unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column), (4, 2));
+        assert_eq!(location.line_text, "3x");
+
+        let error = solve("").unwrap_err();
+        assert_eq!(error.location().unwrap().line, 1);
+    }
+
+    #[test]
+    fn streamed_days_match_their_solvers() {
+        let show = |solutions: Solutions| -> Vec<String> {
+            solutions
+                .into_iter()
+                .map(|answer| answer.map_or_else(|e| e.to_string(), |a| a.to_string()))
+                .collect()
+        };
+
+        for solver in SOLVERS {
+            let Some(stream) = solver.stream else {
+                continue;
+            };
+            let parts: Vec<u8> = (1..=solver.parts).collect();
+
+            let generated =
+                (0..20).map(|seed| (solver.generate)(&mut Rng::new(seed), 1 + seed as usize));
+            // Day 03's generator only makes banks long enough for part 2
+            let short_banks = (solver.day == 3).then(|| "987\n811\n".to_string());
+
+            for input in generated.chain(short_banks) {
+                let whole = show((solver.solve)(&input, &parts).unwrap());
+                let streamed = show(stream(&mut input.as_bytes(), &parts).unwrap());
+                assert_eq!(streamed, whole, "day {:02} on\n{input}", solver.day);
+            }
+        }
+    }
+}
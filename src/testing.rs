@@ -0,0 +1,152 @@
+//! Support for the tests generated by [`test_with_files!`](crate::test_with_files) and
+//! [`test_rejects!`](crate::test_rejects).
+
+use crate::Solution;
+use crate::answers::{self, Answers, Expected, PERSONAL_INPUT, SAMPLE};
+use crate::log;
+use crate::parse::normalize;
+use crate::store::{self, Store};
+use std::fs;
+use std::io::{self, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+fn repository_file(path: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// Reads `inputs/<file>` at run time, so tests still build when a personal input is absent.
+pub fn read_input(file: &str) -> Option<String> {
+    let path = repository_file("inputs").join(file);
+
+    match fs::read_to_string(&path) {
+        Ok(input) => Some(input),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => panic!("error reading `{}`: {e}", path.display()),
+    }
+}
+
+/// Reports a test that had nothing to check. Written straight to stderr because the test
+/// harness swallows the output of passing tests.
+pub fn skip(test: &str, reason: &str) {
+    let _ = writeln!(io::stderr(), "skipping {test}: {reason}");
+}
+
+/// Reports a test whose answer is wrong, as `answers.toml` records it to be.
+pub fn known_failure(test: &str, reason: &str) {
+    let _ = writeln!(io::stderr(), "known failure in {test}: {reason}");
+}
+
+fn read_answers() -> Answers {
+    let path = repository_file("answers.toml");
+    fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|text| Answers::parse(&text).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| panic!("error reading `{}`: {e}", path.display()))
+}
+
+/// Checks that every input `answers.toml` records an answer on for `S` is the sample, the
+/// personal input or one of the extra `cases` the day's tests run, or a part's own version of
+/// one of them.
+pub fn check_cases<S: Solution>(cases: &[&str]) {
+    let answers = read_answers();
+
+    for part in 1..=S::PARTS {
+        let tested: Vec<String> = [SAMPLE, PERSONAL_INPUT]
+            .iter()
+            .chain(cases)
+            .map(|name| answers.input_for(S::DAY, name, part))
+            .collect();
+
+        for input in answers.inputs(S::DAY, part) {
+            assert!(
+                tested.contains(&input),
+                "`answers.toml` has day {:02} part {part} on `{input}`, but no test runs it: add \
+                 `{input}` after the `;` of the day's `test_with_files!`",
+                S::DAY
+            );
+        }
+    }
+}
+
+/// Checks `part` of `S` on the input called `input`, or on the part's own version of it, against
+/// `answers.toml`.
+pub fn check<S: Solution>(input: &str, part: u8) {
+    if let Err(message) = log::init_from_env() {
+        panic!("{message}");
+    }
+
+    let answers = read_answers();
+    let input = answers.input_for(S::DAY, input, part);
+    let test = format!("day {:02} part {part} on {input}", S::DAY);
+
+    let (expected, failure) = match answers.get(S::DAY, &input, part) {
+        Some(Expected::Known(expected)) => (expected, None),
+        Some(Expected::KnownFailure { answer, reason }) => (answer, Some(reason)),
+        Some(Expected::Unknown) | None => return skip(&test, "answer unknown"),
+    };
+
+    let file = answers::input_file(S::DAY, &input);
+    let Some(text) = read_input(&file) else {
+        if answers::is_sample(&input) {
+            panic!("missing sample `inputs/{file}`");
+        }
+        return skip(&test, &format!("`inputs/{file}` not found"));
+    };
+
+    let mut store = Store::default();
+    for file in store::STORE_FILES {
+        if let Some(tsv) = read_input(file) {
+            store.merge(Store::parse(&tsv).unwrap_or_else(|e| panic!("`inputs/{file}`: {e}")));
+        }
+    }
+    store.warn_if_changed(S::DAY, &input, &text);
+
+    let text = normalize(&text);
+    let answer = S::parse(&text).and_then(|parsed| match part {
+        1 => S::part1(&parsed),
+        _ => S::part2(&parsed),
+    });
+
+    match failure {
+        None => assert_eq!(
+            &answer.unwrap_or_else(|e| panic!("{e}")),
+            expected,
+            "{test}"
+        ),
+        Some(_) if answer.as_ref().is_ok_and(|answer| answer == expected) => {
+            panic!("{test} is right now, take its `fails` marker out of `answers.toml`")
+        }
+        Some(reason) => known_failure(&test, reason),
+    }
+}
+
+/// Checks that `S` rejects `input`, when parsing it or else in every part, with an error located
+/// at `line` and `column`.
+pub fn check_rejects<S: Solution>(input: &str, line: usize, column: usize) {
+    let input = normalize(input);
+    let test = format!("day {:02} on {input:?}", S::DAY);
+
+    let errors = match S::parse(&input) {
+        Err(e) => vec![e],
+        Ok(parsed) => (1..=S::PARTS)
+            .map(|part| {
+                let answer = match part {
+                    1 => S::part1(&parsed),
+                    _ => S::part2(&parsed),
+                };
+                answer.expect_err(&format!("{test}: part {part} accepted the input"))
+            })
+            .collect(),
+    };
+
+    for error in errors {
+        let Some(location) = error.location() else {
+            panic!("{test}: the error doesn't say where: {error}");
+        };
+        assert_eq!(
+            (location.line, location.column),
+            (line, column),
+            "{test}: {error}"
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! The days used as a library, the way other tools reuse their parsers and algorithms.
+
+use aoc_2025::{day02, day05, day11};
+
+#[test]
+fn solves_a_day_through_its_module() {
+    let problem = day05::parse("3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n").unwrap();
+
+    assert_eq!(day05::part1(&problem).unwrap().to_string(), "3");
+    assert_eq!(day05::part2(&problem).unwrap().to_string(), "14");
+}
+
+#[test]
+fn reuses_domain_types_and_helpers() {
+    let problem = day05::parse("10-14\n3-5\n12-18\n6-8\n\n17\n").unwrap();
+    let fresh_ids = day05::fresh_ids(&problem);
+    assert_eq!(fresh_ids.iter().collect::<Vec<_>>(), [3..=8, 10..=18]);
+    assert!(fresh_ids.contains(17));
+
+    assert_eq!(day02::sum_doubled(11..=22), 33);
+
+    let graph = day11::parse("you: a b\na: out\nb: out\n").unwrap();
+    assert_eq!(day11::paths_to_other("you", "out", &graph).unwrap(), 2);
+}